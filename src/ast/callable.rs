@@ -0,0 +1,15 @@
+use std::fmt::Debug;
+
+use crate::ast::object::Object;
+use crate::interpreter::{Interpreter, RuntimeError};
+
+/// Anything a call expression can invoke.
+pub trait Callable: Debug {
+    fn arity(&self) -> usize;
+
+    fn call(
+        &self,
+        interpreter: &mut Interpreter,
+        arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError>;
+}
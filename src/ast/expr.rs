@@ -1,12 +1,23 @@
+use crate::ast::interner::Symbol;
 use crate::ast::token::Token;
 
-use super::stmt::{ExpressionStmt, PrintStmt, VarStmt};
+use super::stmt::{ExpressionStmt, MatchStmt, PrintStmt, VarStmt};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expr {
     Binary(Box<BinaryExpr>),
+    Call(Box<CallExpr>),
+    Comma(Box<CommaExpr>),
+    Conditional(Box<ConditionalExpr>),
+    Get(Box<GetExpr>),
     Grouping(Box<GroupingExpr>),
+    Index(Box<IndexExpr>),
+    IndexSet(Box<IndexSetExpr>),
+    IndexUpdate(Box<IndexUpdateExpr>),
+    Interpolation(Box<InterpolationExpr>),
+    List(Box<ListExpr>),
     Literal(Box<LiteralExpr>),
+    Map(Box<MapExpr>),
     Variable(Box<VariableExpr>),
     Unary(Box<UnaryExpr>),
     Unhandled,
@@ -16,8 +27,18 @@ impl Expr {
     pub fn accept<R>(&self, visitor: &mut dyn Visitor<R>) -> R {
         match self {
             Expr::Binary(expr) => visitor.visit_binary_expr(expr),
+            Expr::Call(expr) => visitor.visit_call_expr(expr),
+            Expr::Comma(expr) => visitor.visit_comma_expr(expr),
+            Expr::Conditional(expr) => visitor.visit_conditional_expr(expr),
+            Expr::Get(expr) => visitor.visit_get_expr(expr),
             Expr::Grouping(expr) => visitor.visit_grouping_expr(expr),
+            Expr::Index(expr) => visitor.visit_index_expr(expr),
+            Expr::IndexSet(expr) => visitor.visit_index_set_expr(expr),
+            Expr::IndexUpdate(expr) => visitor.visit_index_update_expr(expr),
+            Expr::Interpolation(expr) => visitor.visit_interpolation_expr(expr),
+            Expr::List(expr) => visitor.visit_list_expr(expr),
             Expr::Literal(expr) => visitor.visit_literal_expr(expr),
+            Expr::Map(expr) => visitor.visit_map_expr(expr),
             Expr::Unary(expr) => visitor.visit_unary_expr(expr),
             Expr::Variable(expr) => visitor.visit_variable_expr(expr),
             _ => {
@@ -30,12 +51,12 @@ impl Expr {
 #[derive(Debug, Clone, PartialEq)]
 pub struct BinaryExpr {
     pub left: Box<Expr>,
-    pub operator: Token,
+    pub operator: Token<'static>,
     pub right: Box<Expr>,
 }
 
 impl BinaryExpr {
-    pub fn new(left: Expr, operator: Token, right: Expr) -> Self {
+    pub fn new(left: Expr, operator: Token<'static>, right: Expr) -> Self {
         Self {
             left: Box::new(left),
             operator,
@@ -44,6 +65,72 @@ impl BinaryExpr {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallExpr {
+    pub callee: Box<Expr>,
+    pub paren: Token<'static>,
+    pub arguments: Vec<Expr>,
+}
+
+impl CallExpr {
+    pub fn new(callee: Expr, paren: Token<'static>, arguments: Vec<Expr>) -> Self {
+        Self {
+            callee: Box::new(callee),
+            paren,
+            arguments,
+        }
+    }
+}
+
+/// `left, right`: evaluates both and yields `right`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommaExpr {
+    pub left: Box<Expr>,
+    pub right: Box<Expr>,
+}
+
+impl CommaExpr {
+    pub fn new(left: Expr, right: Expr) -> Self {
+        Self {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+}
+
+/// `condition ? then_branch : else_branch`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConditionalExpr {
+    pub condition: Box<Expr>,
+    pub then_branch: Box<Expr>,
+    pub else_branch: Box<Expr>,
+}
+
+impl ConditionalExpr {
+    pub fn new(condition: Expr, then_branch: Expr, else_branch: Expr) -> Self {
+        Self {
+            condition: Box::new(condition),
+            then_branch: Box::new(then_branch),
+            else_branch: Box::new(else_branch),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetExpr {
+    pub object: Box<Expr>,
+    pub name: Token<'static>,
+}
+
+impl GetExpr {
+    pub fn new(object: Expr, name: Token<'static>) -> Self {
+        Self {
+            object: Box::new(object),
+            name,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct GroupingExpr {
     pub expr: Box<Expr>,
@@ -57,9 +144,98 @@ impl GroupingExpr {
     }
 }
 
+/// `object[index]`. `bracket` is the opening `[`, kept for error reporting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexExpr {
+    pub object: Box<Expr>,
+    pub bracket: Token<'static>,
+    pub index: Box<Expr>,
+}
+
+impl IndexExpr {
+    pub fn new(object: Expr, bracket: Token<'static>, index: Expr) -> Self {
+        Self {
+            object: Box::new(object),
+            bracket,
+            index: Box::new(index),
+        }
+    }
+}
+
+/// `object[index] = value`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexSetExpr {
+    pub object: Box<Expr>,
+    pub bracket: Token<'static>,
+    pub index: Box<Expr>,
+    pub value: Box<Expr>,
+}
+
+impl IndexSetExpr {
+    pub fn new(object: Expr, bracket: Token<'static>, index: Expr, value: Expr) -> Self {
+        Self {
+            object: Box::new(object),
+            bracket,
+            index: Box::new(index),
+            value: Box::new(value),
+        }
+    }
+}
+
+/// `object[index] op= value`, `++object[index]` or `object[index]++`. The
+/// object and index are evaluated once. `operator` is the `+=`, `++` or
+/// similar token, `value` is `1` for increments and decrements, and
+/// `postfix` makes the expression yield the element's old value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexUpdateExpr {
+    pub object: Box<Expr>,
+    pub bracket: Token<'static>,
+    pub index: Box<Expr>,
+    pub operator: Token<'static>,
+    pub value: Box<Expr>,
+    pub postfix: bool,
+}
+
+impl IndexUpdateExpr {
+    pub fn new(target: IndexExpr, operator: Token<'static>, value: Expr, postfix: bool) -> Self {
+        Self {
+            object: target.object,
+            bracket: target.bracket,
+            index: target.index,
+            operator,
+            value: Box::new(value),
+            postfix,
+        }
+    }
+}
+
+/// A string with embedded expressions. `parts` alternates string segments
+/// and the expressions between them, starting and ending with a segment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterpolationExpr {
+    pub parts: Vec<Expr>,
+}
+
+impl InterpolationExpr {
+    pub fn new(parts: Vec<Expr>) -> Self {
+        Self { parts }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListExpr {
+    pub elements: Vec<Expr>,
+}
+
+impl ListExpr {
+    pub fn new(elements: Vec<Expr>) -> Self {
+        Self { elements }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum LiteralExpr {
-    Str(String),
+    Str(Symbol),
     Num(f64),
     Bool(bool),
     Nil,
@@ -83,6 +259,12 @@ impl LiteralExpr {
 
 impl From<String> for LiteralExpr {
     fn from(value: String) -> Self {
+        LiteralExpr::Str(value.into())
+    }
+}
+
+impl From<Symbol> for LiteralExpr {
+    fn from(value: Symbol) -> Self {
         LiteralExpr::Str(value)
     }
 }
@@ -99,25 +281,39 @@ impl From<bool> for LiteralExpr {
     }
 }
 
+/// `{key: value, ...}`. `brace` is the opening `{`, kept for reporting
+/// unhashable keys.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapExpr {
+    pub brace: Token<'static>,
+    pub entries: Vec<(Expr, Expr)>,
+}
+
+impl MapExpr {
+    pub fn new(brace: Token<'static>, entries: Vec<(Expr, Expr)>) -> Self {
+        Self { brace, entries }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct VariableExpr {
-    pub name: Token,
+    pub name: Token<'static>,
 }
 
 impl VariableExpr {
-    pub fn new(name: Token) -> Self {
+    pub fn new(name: Token<'static>) -> Self {
         Self { name }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnaryExpr {
-    pub operator: Token,
+    pub operator: Token<'static>,
     pub right: Box<Expr>,
 }
 
 impl UnaryExpr {
-    pub fn new(operator: Token, right: Expr) -> Self {
+    pub fn new(operator: Token<'static>, right: Expr) -> Self {
         Self {
             operator,
             right: Box::new(right),
@@ -133,5 +329,16 @@ pub trait Visitor<R> {
     fn visit_expression_stmt(&mut self, stmt: &ExpressionStmt) -> R;
     fn visit_print_stmt(&mut self, stmt: &PrintStmt) -> R;
     fn visit_var_stmt(&mut self, stmt: &VarStmt) -> R;
+    fn visit_match_stmt(&mut self, stmt: &MatchStmt) -> R;
     fn visit_variable_expr(&mut self, expr: &VariableExpr) -> R;
+    fn visit_interpolation_expr(&mut self, expr: &InterpolationExpr) -> R;
+    fn visit_list_expr(&mut self, expr: &ListExpr) -> R;
+    fn visit_index_expr(&mut self, expr: &IndexExpr) -> R;
+    fn visit_index_set_expr(&mut self, expr: &IndexSetExpr) -> R;
+    fn visit_get_expr(&mut self, expr: &GetExpr) -> R;
+    fn visit_call_expr(&mut self, expr: &CallExpr) -> R;
+    fn visit_map_expr(&mut self, expr: &MapExpr) -> R;
+    fn visit_conditional_expr(&mut self, expr: &ConditionalExpr) -> R;
+    fn visit_comma_expr(&mut self, expr: &CommaExpr) -> R;
+    fn visit_index_update_expr(&mut self, expr: &IndexUpdateExpr) -> R;
 }
@@ -0,0 +1,178 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+use crate::ast::callable::Callable;
+use crate::ast::object::Object;
+use crate::ast::token::Token;
+use crate::interpreter::{Interpreter, RuntimeError};
+
+/// A growable, mutable list. Lists are shared by reference, so every copy
+/// of the `Object` holding an `Rc<List>` sees the same elements.
+#[derive(Debug, Default)]
+pub struct List {
+    pub elements: RefCell<Vec<Object>>,
+    printing: Cell<bool>,
+}
+
+impl List {
+    pub fn new(elements: Vec<Object>) -> Self {
+        Self {
+            elements: RefCell::new(elements),
+            printing: Cell::new(false),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.borrow().is_empty()
+    }
+
+    pub fn get(&self, token: &Token<'static>, index: &Object) -> Result<Object, RuntimeError> {
+        let index = checked_index(token, index, self.len())?;
+        Ok(self.elements.borrow()[index].clone())
+    }
+
+    pub fn set(
+        &self,
+        token: &Token<'static>,
+        index: &Object,
+        value: Object,
+    ) -> Result<(), RuntimeError> {
+        let index = checked_index(token, index, self.len())?;
+        self.elements.borrow_mut()[index] = value;
+        Ok(())
+    }
+
+    fn write_elements(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, element) in self.elements.borrow().iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            element.fmt_element(f)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // A list that contains itself prints the inner reference as `[...]`.
+        if self.printing.replace(true) {
+            return write!(f, "[...]");
+        }
+
+        let result = self.write_elements(f);
+
+        self.printing.set(false);
+        result
+    }
+}
+
+/// Converts `index` to a position in `0..bound`, reporting at `token`.
+pub fn checked_index(
+    token: &Token<'static>,
+    index: &Object,
+    bound: usize,
+) -> Result<usize, RuntimeError> {
+    let Some(&number) = index.get_value::<f64>() else {
+        return Err(RuntimeError::new(
+            token.clone(),
+            "List index must be a number.".to_string(),
+        ));
+    };
+
+    if number.fract() != 0.0 {
+        return Err(RuntimeError::new(
+            token.clone(),
+            "List index must be an integer.".to_string(),
+        ));
+    }
+
+    if number < 0.0 || number >= bound as f64 {
+        return Err(RuntimeError::new(
+            token.clone(),
+            format!("List index {} is out of range.", number),
+        ));
+    }
+
+    Ok(number as usize)
+}
+
+/// A built-in list method bound to the list it was looked up on.
+#[derive(Debug)]
+pub struct ListMethod {
+    pub list: Rc<List>,
+    pub name: Token<'static>,
+}
+
+impl ListMethod {
+    pub const NAMES: [&'static str; 6] = ["push", "pop", "len", "insert", "remove", "slice"];
+
+    pub fn new(list: Rc<List>, name: Token<'static>) -> Self {
+        Self { list, name }
+    }
+}
+
+impl Callable for ListMethod {
+    fn arity(&self) -> usize {
+        match self.name.lexeme {
+            "pop" | "len" => 0,
+            "push" | "remove" => 1,
+            "insert" | "slice" => 2,
+            _ => unreachable!("Unknown list method {}", self.name.lexeme),
+        }
+    }
+
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        let mut arguments = arguments.into_iter();
+        let len = self.list.len();
+
+        match self.name.lexeme {
+            "push" => {
+                let value = arguments.next().unwrap();
+                self.list.elements.borrow_mut().push(value);
+                Ok(Object::new(None::<()>))
+            }
+            "pop" => self.list.elements.borrow_mut().pop().ok_or_else(|| {
+                RuntimeError::new(
+                    self.name.clone(),
+                    "Can't pop from an empty list.".to_string(),
+                )
+            }),
+            "len" => Ok(Object::new(len as f64)),
+            "insert" => {
+                let index = checked_index(&self.name, &arguments.next().unwrap(), len + 1)?;
+                let value = arguments.next().unwrap();
+                self.list.elements.borrow_mut().insert(index, value);
+                Ok(Object::new(None::<()>))
+            }
+            "remove" => {
+                let index = checked_index(&self.name, &arguments.next().unwrap(), len)?;
+                Ok(self.list.elements.borrow_mut().remove(index))
+            }
+            "slice" => {
+                let start = checked_index(&self.name, &arguments.next().unwrap(), len + 1)?;
+                let end = checked_index(&self.name, &arguments.next().unwrap(), len + 1)?;
+                if start > end {
+                    return Err(RuntimeError::new(
+                        self.name.clone(),
+                        "Slice start must not be after its end.".to_string(),
+                    ));
+                }
+
+                let elements = self.list.elements.borrow()[start..end].to_vec();
+                Ok(Object::new(Rc::new(List::new(elements))))
+            }
+            _ => unreachable!("Unknown list method {}", self.name.lexeme),
+        }
+    }
+}
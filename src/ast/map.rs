@@ -0,0 +1,211 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::ast::callable::Callable;
+use crate::ast::list::List;
+use crate::ast::object::Object;
+use crate::ast::string::Str;
+use crate::ast::token::Token;
+use crate::interpreter::{Interpreter, RuntimeError};
+
+/// The hashable subset of runtime values. Two keys are equal exactly when
+/// the interpreter would consider the values they came from equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HashKey {
+    Nil,
+    Bool(bool),
+    Num(u64),
+    Str(Str),
+}
+
+impl HashKey {
+    pub fn from_object(token: &Token<'static>, object: &Object) -> Result<Self, RuntimeError> {
+        if object.is::<Option<()>>() {
+            Ok(HashKey::Nil)
+        } else if let Some(value) = object.get_value::<bool>() {
+            Ok(HashKey::Bool(*value))
+        } else if let Some(value) = object.get_value::<f64>() {
+            if value.is_nan() {
+                return Err(RuntimeError::new(
+                    token.clone(),
+                    "NaN can't be used as a map key.".to_string(),
+                ));
+            }
+            // -0 == 0 at runtime, so both must hash the same.
+            let value = if *value == 0.0 { 0.0 } else { *value };
+            Ok(HashKey::Num(value.to_bits()))
+        } else if let Some(value) = object.get_value::<Str>() {
+            Ok(HashKey::Str(value.clone()))
+        } else {
+            Err(RuntimeError::new(
+                token.clone(),
+                "Map keys must be strings, numbers, booleans or nil.".to_string(),
+            ))
+        }
+    }
+
+    pub fn to_object(&self) -> Object {
+        match self {
+            HashKey::Nil => Object::new(None::<()>),
+            HashKey::Bool(value) => Object::new(*value),
+            HashKey::Num(bits) => Object::new(f64::from_bits(*bits)),
+            HashKey::Str(value) => Object::new(value.clone()),
+        }
+    }
+}
+
+impl fmt::Display for HashKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_object())
+    }
+}
+
+/// A mutable map that remembers insertion order. Like lists, maps are
+/// shared by reference.
+#[derive(Debug, Default)]
+pub struct Map {
+    entries: RefCell<Vec<(HashKey, Object)>>,
+    index: RefCell<HashMap<HashKey, usize>>,
+    printing: Cell<bool>,
+}
+
+impl Map {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+
+    pub fn contains(&self, key: &HashKey) -> bool {
+        self.index.borrow().contains_key(key)
+    }
+
+    pub fn get(&self, token: &Token<'static>, key: &HashKey) -> Result<Object, RuntimeError> {
+        match self.index.borrow().get(key) {
+            Some(&i) => Ok(self.entries.borrow()[i].1.clone()),
+            None => Err(missing_key(token, key)),
+        }
+    }
+
+    pub fn set(&self, key: HashKey, value: Object) {
+        let existing = self.index.borrow().get(&key).copied();
+
+        match existing {
+            Some(i) => self.entries.borrow_mut()[i].1 = value,
+            None => {
+                let mut entries = self.entries.borrow_mut();
+                self.index.borrow_mut().insert(key.clone(), entries.len());
+                entries.push((key, value));
+            }
+        }
+    }
+
+    pub fn remove(&self, token: &Token<'static>, key: &HashKey) -> Result<Object, RuntimeError> {
+        let Some(i) = self.index.borrow_mut().remove(key) else {
+            return Err(missing_key(token, key));
+        };
+
+        let (_, value) = self.entries.borrow_mut().remove(i);
+        for position in self.index.borrow_mut().values_mut() {
+            if *position > i {
+                *position -= 1;
+            }
+        }
+
+        Ok(value)
+    }
+
+    pub fn keys(&self) -> Vec<Object> {
+        let entries = self.entries.borrow();
+        entries.iter().map(|(key, _)| key.to_object()).collect()
+    }
+
+    pub fn values(&self) -> Vec<Object> {
+        let entries = self.entries.borrow();
+        entries.iter().map(|(_, value)| value.clone()).collect()
+    }
+
+    fn write_entries(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.entries.borrow().iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            key.to_object().fmt_element(f)?;
+            write!(f, ": ")?;
+            value.fmt_element(f)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.printing.replace(true) {
+            return write!(f, "{{...}}");
+        }
+
+        let result = self.write_entries(f);
+
+        self.printing.set(false);
+        result
+    }
+}
+
+fn missing_key(token: &Token<'static>, key: &HashKey) -> RuntimeError {
+    RuntimeError::new(token.clone(), format!("Undefined key '{}'.", key))
+}
+
+/// A built-in map method bound to the map it was looked up on.
+#[derive(Debug)]
+pub struct MapMethod {
+    pub map: Rc<Map>,
+    pub name: Token<'static>,
+}
+
+impl MapMethod {
+    pub const NAMES: [&'static str; 5] = ["keys", "values", "has", "remove", "len"];
+
+    pub fn new(map: Rc<Map>, name: Token<'static>) -> Self {
+        Self { map, name }
+    }
+}
+
+impl Callable for MapMethod {
+    fn arity(&self) -> usize {
+        match self.name.lexeme {
+            "keys" | "values" | "len" => 0,
+            "has" | "remove" => 1,
+            _ => unreachable!("Unknown map method {}", self.name.lexeme),
+        }
+    }
+
+    fn call(
+        &self,
+        _interpreter: &mut Interpreter,
+        arguments: Vec<Object>,
+    ) -> Result<Object, RuntimeError> {
+        match self.name.lexeme {
+            "keys" => Ok(Object::new(Rc::new(List::new(self.map.keys())))),
+            "values" => Ok(Object::new(Rc::new(List::new(self.map.values())))),
+            "len" => Ok(Object::new(self.map.len() as f64)),
+            "has" => {
+                let key = HashKey::from_object(&self.name, &arguments[0])?;
+                Ok(Object::new(self.map.contains(&key)))
+            }
+            "remove" => {
+                let key = HashKey::from_object(&self.name, &arguments[0])?;
+                self.map.remove(&self.name, &key)
+            }
+            _ => unreachable!("Unknown map method {}", self.name.lexeme),
+        }
+    }
+}
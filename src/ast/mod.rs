@@ -1,7 +1,12 @@
+pub mod callable;
 pub mod expr;
+pub mod interner;
+pub mod list;
+pub mod map;
 pub mod object;
 pub mod parser;
 pub mod printer;
 pub mod scanner;
 pub mod stmt;
+pub mod string;
 pub mod token;
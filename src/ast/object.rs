@@ -1,4 +1,11 @@
 use std::any::Any;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::ast::callable::Callable;
+use crate::ast::list::List;
+use crate::ast::map::Map;
+use crate::ast::string::Str;
 
 #[derive(Debug)]
 pub struct Object {
@@ -26,15 +33,81 @@ impl Object {
         }
     }
 
-    pub fn to_string(&self) -> String {
-        if self.is::<f64>() {
-            self.get_value::<f64>().unwrap().to_string()
-        } else if self.is::<String>() {
-            self.get_value::<String>().unwrap().to_string()
-        } else if self.is::<bool>() {
-            self.get_value::<bool>().unwrap().to_string()
+    /// Formats the value as it appears inside a list or map. Strings are
+    /// quoted, so `["1", 1]` doesn't print as `[1, 1]`.
+    pub fn fmt_element(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.get_value::<Str>() {
+            Some(value) => write_quoted(f, value),
+            None => write!(f, "{}", self),
+        }
+    }
+}
+
+/// Writes `value` as a string literal the scanner would read back.
+fn write_quoted(f: &mut fmt::Formatter, value: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => write!(f, "\\n")?,
+            '\t' => write!(f, "\\t")?,
+            '\r' => write!(f, "\\r")?,
+            '\0' => write!(f, "\\0")?,
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '$' if chars.peek() == Some(&'{') => write!(f, "\\$")?,
+            c if c.is_control() => write!(f, "\\u{{{:x}}}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+
+    write!(f, "\"")
+}
+
+/// Numbers, booleans and nil are copied. Strings are immutable, so a clone
+/// shares the interned symbol or the `Rc<str>` text. Lists, maps and callables are
+/// reference types, so a clone shares the same underlying value.
+impl Clone for Object {
+    fn clone(&self) -> Self {
+        if let Some(value) = self.get_value::<f64>() {
+            Object::new(*value)
+        } else if let Some(value) = self.get_value::<bool>() {
+            Object::new(*value)
+        } else if let Some(value) = self.get_value::<Str>() {
+            Object::new(value.clone())
+        } else if self.is::<Option<()>>() {
+            Object::new(None::<()>)
+        } else if self.is::<()>() {
+            Object::new(())
+        } else if let Some(value) = self.get_value::<Rc<List>>() {
+            Object::new(Rc::clone(value))
+        } else if let Some(value) = self.get_value::<Rc<Map>>() {
+            Object::new(Rc::clone(value))
+        } else if let Some(value) = self.get_value::<Rc<dyn Callable>>() {
+            Object::new(Rc::clone(value))
+        } else {
+            panic!("Cannot clone object of type {}", self.type_name);
+        }
+    }
+}
+
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(value) = self.get_value::<f64>() {
+            write!(f, "{}", value)
+        } else if let Some(value) = self.get_value::<Str>() {
+            write!(f, "{}", value)
+        } else if let Some(value) = self.get_value::<bool>() {
+            write!(f, "{}", value)
+        } else if let Some(value) = self.get_value::<Rc<List>>() {
+            write!(f, "{}", value)
+        } else if let Some(value) = self.get_value::<Rc<Map>>() {
+            write!(f, "{}", value)
+        } else if self.is::<Rc<dyn Callable>>() {
+            write!(f, "<native fn>")
         } else {
-            "nil".to_string()
+            write!(f, "nil")
         }
     }
 }
@@ -1,8 +1,11 @@
-use crate::ast::expr::{BinaryExpr, Expr, GroupingExpr, LiteralExpr, UnaryExpr};
+use crate::ast::expr::{BinaryExpr, Expr, GroupingExpr, InterpolationExpr, LiteralExpr, UnaryExpr};
+use crate::ast::expr::{CallExpr, CommaExpr, ConditionalExpr, GetExpr, IndexExpr, IndexSetExpr};
+use crate::ast::expr::{IndexUpdateExpr, ListExpr, MapExpr};
 use crate::ast::token::{Token, TokenType};
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
 
-use super::stmt::{ExpressionStmt, PrintStmt, Stmt, VarStmt};
+use super::stmt::{ExpressionStmt, MatchArm, MatchStmt, Pattern, PrintStmt, Stmt, VarStmt};
 #[derive(Debug)]
 pub struct ParseError;
 
@@ -14,18 +17,33 @@ impl std::fmt::Display for ParseError {
 
 impl std::error::Error for ParseError {}
 
-pub struct Parser {
-    pub tokens: Vec<Token>,
+/// Parses statements from any token iterator, pulling tokens only as far
+/// as the grammar needs to look ahead. `current` counts the tokens consumed
+/// so far.
+pub struct Parser<I: Iterator> {
+    pub tokens: I,
+    pub lookahead: VecDeque<I::Item>,
+    pub last: Option<I::Item>,
     pub current: usize,
     pub error: Cell<bool>,
+    pub errors: RefCell<Vec<String>>,
+    /// Set after an error until the parser recovers, so a single mistake
+    /// isn't reported again by every rule that trips over it.
+    pub panic_mode: Cell<bool>,
+    pub warnings: Vec<String>,
 }
 
-impl Parser {
-    pub fn new(tokens: Vec<Token>) -> Self {
+impl<'src, I: Iterator<Item = Token<'src>>> Parser<I> {
+    pub fn new(tokens: impl IntoIterator<Item = Token<'src>, IntoIter = I>) -> Self {
         Parser {
-            tokens,
+            tokens: tokens.into_iter(),
+            lookahead: VecDeque::new(),
+            last: None,
             current: 0,
             error: Cell::new(false),
+            errors: RefCell::new(Vec::new()),
+            panic_mode: Cell::new(false),
+            warnings: Vec::new(),
         }
     }
 
@@ -42,16 +60,36 @@ impl Parser {
     }
 
     pub fn declaration(&mut self) -> Stmt {
-        if self.match_tokens(&[TokenType::Var]) {
-            return self.var_declaration();
+        let errors = self.errors.borrow().len();
+        let start = self.current;
+
+        let stmt = if self.match_tokens(&[TokenType::Var]) {
+            self.var_declaration()
+        } else {
+            self.statement()
+        };
+
+        // After an error, skip to the next statement unless this one ended
+        // cleanly. This also guarantees progress when nothing was consumed.
+        if self.errors.borrow().len() > errors {
+            let ended = self.last.as_ref().is_some_and(|token| {
+                matches!(
+                    token.token_type,
+                    TokenType::Semicolon | TokenType::RightBrace
+                )
+            });
+            if self.current == start || !ended {
+                self.synchronize();
+            }
         }
+        self.panic_mode.set(false);
 
-        self.statement()
+        stmt
     }
 
     pub fn var_declaration(&mut self) -> Stmt {
         let result = self.consume(TokenType::Identifier, "Expect variable name.");
-        let name = result.unwrap().clone();
+        let name = result.unwrap().to_static();
 
         let initializer = if self.match_tokens(&[TokenType::Equal]) {
             Some(self.expression())
@@ -65,7 +103,7 @@ impl Parser {
         );
 
         Stmt::Var(VarStmt {
-            name: name,
+            name,
             initializer: initializer.unwrap(),
         })
     }
@@ -75,25 +113,151 @@ impl Parser {
             return self.print_statement();
         }
 
+        if self.match_tokens(&[TokenType::Match]) {
+            return self.match_statement();
+        }
+
+        if self.check(TokenType::LeftBrace) {
+            // Parse it as a map anyway so the rest of the statement is
+            // checked too.
+            let brace = self.peek().clone();
+            let _ = self.error(
+                &brace,
+                "'{' at the start of a statement is reserved for blocks. Wrap a map in parentheses.",
+            );
+        }
+
         self.expression_statement()
     }
 
+    /// Parses the rest of a `match` statement after its keyword. Each arm
+    /// runs a single statement. Arms after an unguarded `_` can never run,
+    /// so they are reported as warnings.
+    pub fn match_statement(&mut self) -> Stmt {
+        let _ = self.consume(TokenType::LeftParen, "Expect '(' after 'match'.");
+        let subject = self.expression();
+        let _ = self.consume(TokenType::RightParen, "Expect ')' after match subject.");
+        let _ = self.consume(TokenType::LeftBrace, "Expect '{' before match arms.");
+
+        let mut arms = Vec::new();
+        let mut exhausted = false;
+
+        while !self.check(TokenType::RightBrace) && !self.is_at_end() {
+            if exhausted {
+                let token = self.peek().clone();
+                self.warning(&token, "Unreachable match arm after wildcard.");
+            }
+
+            let arm = self.match_arm();
+            if arm.guard.is_none() && arm.patterns.contains(&Pattern::Wildcard) {
+                exhausted = true;
+            }
+            arms.push(arm);
+        }
+
+        let _ = self.consume(TokenType::RightBrace, "Expect '}' after match arms.");
+
+        Stmt::Match(MatchStmt::new(subject, arms))
+    }
+
+    pub fn match_arm(&mut self) -> MatchArm {
+        // Invalid patterns are left out, so the arm can't mark the match
+        // exhausted and cause bogus unreachable warnings.
+        let mut patterns: Vec<Pattern> = self.pattern().into_iter().collect();
+        while self.match_tokens(&[TokenType::Or]) {
+            patterns.extend(self.pattern());
+        }
+
+        let guard = if self.match_tokens(&[TokenType::If]) {
+            Some(self.expression())
+        } else {
+            None
+        };
+
+        let arrow = self.consume(TokenType::FatArrow, "Expect '=>' after match pattern.");
+        // `match (1) { 1 }` has no body to parse; leave the '}' to close
+        // the match.
+        let body = if arrow.is_err() && self.check(TokenType::RightBrace) {
+            Stmt::Expression(ExpressionStmt {
+                expression: Expr::Literal(Box::new(LiteralExpr::Nil)),
+            })
+        } else {
+            self.statement()
+        };
+
+        MatchArm::new(patterns, guard, body)
+    }
+
+    /// Parses one pattern. An invalid pattern is reported and skipped up to
+    /// the `or`, `if` or `=>` after it, so the rest of the arm still parses.
+    pub fn pattern(&mut self) -> Option<Pattern> {
+        if self.match_tokens(&[TokenType::False]) {
+            return Some(Pattern::Literal(LiteralExpr::Bool(false)));
+        }
+        if self.match_tokens(&[TokenType::True]) {
+            return Some(Pattern::Literal(LiteralExpr::Bool(true)));
+        }
+        if self.match_tokens(&[TokenType::Nil]) {
+            return Some(Pattern::Literal(LiteralExpr::Nil));
+        }
+        if self.match_tokens(&[TokenType::Number, TokenType::String]) {
+            return Some(Pattern::Literal(self.previous().literal.clone().unwrap()));
+        }
+
+        let token = self.peek().clone();
+        if self.match_tokens(&[TokenType::Minus]) && self.match_tokens(&[TokenType::Number]) {
+            if let Some(LiteralExpr::Num(value)) = self.previous().literal {
+                return Some(Pattern::Literal(LiteralExpr::Num(-value)));
+            }
+        }
+        if token.token_type == TokenType::Identifier && token.lexeme == "_" {
+            self.advance();
+            return Some(Pattern::Wildcard);
+        }
+
+        let message = if token.token_type == TokenType::Identifier {
+            "Binding patterns are not supported yet."
+        } else {
+            "Expect literal pattern or '_'."
+        };
+        let _ = self.error(&token, message);
+        self.skip_pattern();
+        self.panic_mode.set(false);
+        None
+    }
+
+    fn skip_pattern(&mut self) {
+        let mut depth = 0usize;
+
+        while !self.is_at_end() {
+            match self.peek().token_type {
+                TokenType::Or | TokenType::If | TokenType::FatArrow | TokenType::RightBrace
+                    if depth == 0 =>
+                {
+                    return;
+                }
+                TokenType::LeftParen | TokenType::LeftBracket | TokenType::LeftBrace => depth += 1,
+                TokenType::RightParen | TokenType::RightBracket | TokenType::RightBrace => {
+                    depth = depth.saturating_sub(1)
+                }
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+
     pub fn print_statement(&mut self) -> Stmt {
         let expression = self.expression();
-        self.consume(TokenType::Semicolon, "Expect ';' after value.");
+        let _ = self.consume(TokenType::Semicolon, "Expect ';' after value.");
 
-        Stmt::Print(PrintStmt {
-            expression: expression,
-        })
+        Stmt::Print(PrintStmt { expression })
     }
 
     pub fn expression_statement(&mut self) -> Stmt {
         let expression = self.expression();
-        self.consume(TokenType::Semicolon, "Expect ';' after expression.");
+        let _ = self.consume(TokenType::Semicolon, "Expect ';' after expression.");
 
-        Stmt::Expression(ExpressionStmt {
-            expression: expression,
-        })
+        Stmt::Expression(ExpressionStmt { expression })
     }
 
     pub fn synchronize(&mut self) {
@@ -123,14 +287,160 @@ impl Parser {
     }
 
     pub fn expression(&mut self) -> Expr {
-        self.equality()
+        self.comma()
+    }
+
+    /// The comma operator has the lowest precedence of all. Argument lists,
+    /// list elements and map entries parse `assignment` instead, so their
+    /// commas still separate items.
+    pub fn comma(&mut self) -> Expr {
+        let mut expr = self.assignment();
+
+        while self.match_tokens(&[TokenType::Comma]) {
+            let right = self.assignment();
+            expr = Expr::Comma(Box::new(CommaExpr::new(expr, right)));
+        }
+
+        expr
+    }
+
+    pub fn assignment(&mut self) -> Expr {
+        let expr = self.conditional();
+
+        if self.match_tokens(&[TokenType::Equal]) {
+            let equals = self.previous().clone();
+            let value = self.assignment();
+
+            if let Expr::Index(index) = expr {
+                let IndexExpr {
+                    object,
+                    bracket,
+                    index,
+                } = *index;
+                return Expr::IndexSet(Box::new(IndexSetExpr {
+                    object,
+                    bracket,
+                    index,
+                    value: Box::new(value),
+                }));
+            }
+
+            let _ = self.error(&equals, "Invalid assignment target.");
+        } else if self.match_tokens(&[
+            TokenType::PlusEqual,
+            TokenType::MinusEqual,
+            TokenType::StarEqual,
+            TokenType::SlashEqual,
+        ]) {
+            let operator = self.previous().to_static();
+            let value = self.assignment();
+            return self.update(expr, operator, value, false);
+        }
+
+        expr
+    }
+
+    /// Builds a compound assignment, increment or decrement of `target`.
+    /// Only indexed elements can be updated in place.
+    pub fn update(
+        &mut self,
+        target: Expr,
+        operator: Token<'static>,
+        value: Expr,
+        postfix: bool,
+    ) -> Expr {
+        if let Expr::Index(index) = target {
+            return Expr::IndexUpdate(Box::new(IndexUpdateExpr::new(
+                *index, operator, value, postfix,
+            )));
+        }
+
+        let _ = self.error(&operator, "Invalid assignment target.");
+        target
+    }
+
+    /// `c ? a : b` is right-associative. The middle operand may be any
+    /// expression, since `?` and `:` bracket it.
+    pub fn conditional(&mut self) -> Expr {
+        let expr = self.bit_or();
+
+        if self.match_tokens(&[TokenType::Question]) {
+            let then_branch = self.expression();
+            if self
+                .consume(
+                    TokenType::Colon,
+                    "Expect ':' after then branch of conditional.",
+                )
+                .is_err()
+            {
+                return expr;
+            }
+            let else_branch = self.conditional();
+            return Expr::Conditional(Box::new(ConditionalExpr::new(
+                expr,
+                then_branch,
+                else_branch,
+            )));
+        }
+
+        expr
+    }
+
+    /// The bitwise operators sit below equality, as in C, so `a & b == c`
+    /// parses as `a & (b == c)`.
+    pub fn bit_or(&mut self) -> Expr {
+        let mut expr = self.bit_xor();
+
+        while self.match_tokens(&[TokenType::Pipe]) {
+            let operator = self.previous().to_static();
+            let right = self.bit_xor();
+            expr = Expr::Binary(Box::new(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            }));
+        }
+
+        expr
+    }
+
+    pub fn bit_xor(&mut self) -> Expr {
+        let mut expr = self.bit_and();
+
+        while self.match_tokens(&[TokenType::Caret]) {
+            let operator = self.previous().to_static();
+            let right = self.bit_and();
+            expr = Expr::Binary(Box::new(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            }));
+        }
+
+        expr
+    }
+
+    pub fn bit_and(&mut self) -> Expr {
+        let mut expr = self.equality();
+
+        while self.match_tokens(&[TokenType::Ampersand]) {
+            let operator = self.previous().to_static();
+            let right = self.equality();
+            expr = Expr::Binary(Box::new(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            }));
+        }
+
+        expr
     }
 
     pub fn equality(&mut self) -> Expr {
         let mut expr = self.comparison();
 
         while self.match_tokens(&[TokenType::BangEqual, TokenType::EqualEqual]) {
-            let operator = self.previous().clone();
+            let operator = self.previous().to_static();
             let right = self.comparison();
 
             expr = Expr::Binary(Box::new(BinaryExpr {
@@ -143,27 +453,36 @@ impl Parser {
         expr
     }
 
-    pub fn peek(&self) -> &Token {
-        &self.tokens[self.current]
+    pub fn peek(&mut self) -> &Token<'src> {
+        if self.lookahead.is_empty() {
+            let token = self
+                .tokens
+                .next()
+                .expect("Token stream ended without an Eof token");
+            self.lookahead.push_back(token);
+        }
+
+        &self.lookahead[0]
     }
 
-    pub fn is_at_end(&self) -> bool {
+    pub fn is_at_end(&mut self) -> bool {
         self.peek().token_type == TokenType::Eof
     }
 
-    pub fn check(&self, token_type: TokenType) -> bool {
+    pub fn check(&mut self, token_type: TokenType) -> bool {
         if self.is_at_end() {
             return false;
         }
         self.peek().token_type == token_type
     }
 
-    pub fn previous(&self) -> &Token {
-        &self.tokens[self.current - 1]
+    pub fn previous(&self) -> &Token<'src> {
+        self.last.as_ref().expect("No token has been consumed yet")
     }
 
-    pub fn advance(&mut self) -> &Token {
+    pub fn advance(&mut self) -> &Token<'src> {
         if !self.is_at_end() {
+            self.last = self.lookahead.pop_front();
             self.current += 1;
         }
         self.previous()
@@ -180,15 +499,31 @@ impl Parser {
     }
 
     pub fn error(&self, token: &Token, message: &str) -> String {
+        let error = format!("Error at {}: {}", token.lexeme, message);
         self.error.set(true);
-        format!("Error at {}: {}", token.lexeme, message)
+        if !self.panic_mode.replace(true) {
+            self.errors.borrow_mut().push(error.clone());
+        }
+        error
     }
 
-    pub fn consume(&mut self, token_type: TokenType, message: &str) -> Result<&Token, String> {
+    pub fn warning(&mut self, token: &Token, message: &str) {
+        self.warnings.push(format!(
+            "[line {}] Warning at '{}': {}",
+            token.line, token.lexeme, message
+        ));
+    }
+
+    pub fn consume(
+        &mut self,
+        token_type: TokenType,
+        message: &str,
+    ) -> Result<&Token<'src>, String> {
         if self.check(token_type) {
             Ok(self.advance())
         } else {
-            Err(self.error(self.peek(), message))
+            let token = self.peek().clone();
+            Err(self.error(&token, message))
         }
     }
 
@@ -208,10 +543,25 @@ impl Parser {
             return match literal {
                 LiteralExpr::Str(s) => Ok(Expr::Literal(Box::new(LiteralExpr::Str(s)))),
                 LiteralExpr::Num(n) => Ok(Expr::Literal(Box::new(LiteralExpr::Num(n)))),
-                _ => Err("Unexpected literal type".to_string()),
+                _ => {
+                    let token = self.previous().clone();
+                    Err(self.error(&token, "Unexpected literal type"))
+                }
             };
         }
 
+        if self.match_tokens(&[TokenType::Interpolation]) {
+            return self.interpolation();
+        }
+
+        if self.match_tokens(&[TokenType::LeftBracket]) {
+            return self.list();
+        }
+
+        if self.match_tokens(&[TokenType::LeftBrace]) {
+            return self.map();
+        }
+
         if self.match_tokens(&[TokenType::LeftParen]) {
             let expr = self.expression();
             self.consume(TokenType::RightParen, "Expect ')' after expression.")?;
@@ -220,12 +570,13 @@ impl Parser {
             })));
         }
 
-        Err("Expect expression.".to_string())
+        let token = self.peek().clone();
+        Err(self.error(&token, "Expect expression."))
     }
 
     pub fn unary(&mut self) -> Expr {
-        if self.match_tokens(&[TokenType::Bang, TokenType::Minus]) {
-            let operator = self.previous().clone();
+        if self.match_tokens(&[TokenType::Bang, TokenType::Minus, TokenType::Tilde]) {
+            let operator = self.previous().to_static();
             let right = self.unary();
             return Expr::Unary(Box::new(UnaryExpr {
                 operator,
@@ -233,18 +584,152 @@ impl Parser {
             }));
         }
 
-        if let Ok(expr) = self.primary() {
-            return expr;
-        } else {
-            panic!("Unary error");
+        self.power()
+    }
+
+    /// `**` binds tighter than unary minus and is right-associative, so
+    /// `-2 ** 2` is `-(2 ** 2)` and `2 ** 3 ** 2` is `2 ** (3 ** 2)`. The
+    /// exponent goes through `unary` to allow `2 ** -1`.
+    pub fn power(&mut self) -> Expr {
+        let expr = self.prefix();
+
+        if self.match_tokens(&[TokenType::StarStar]) {
+            let operator = self.previous().to_static();
+            let right = self.unary();
+            return Expr::Binary(Box::new(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            }));
         }
+
+        expr
+    }
+
+    /// Prefix `++` and `--` take their target from the call level, so
+    /// `++xs[0] ** 2` is `(++xs[0]) ** 2`.
+    pub fn prefix(&mut self) -> Expr {
+        if self.match_tokens(&[TokenType::PlusPlus, TokenType::MinusMinus]) {
+            let operator = self.previous().to_static();
+            let target = self.postfix();
+            let one = Expr::Literal(Box::new(LiteralExpr::Num(1.0)));
+            return self.update(target, operator, one, false);
+        }
+
+        self.postfix()
+    }
+
+    pub fn postfix(&mut self) -> Expr {
+        // The error is already recorded, and the program won't run, so a
+        // nil literal stands in for the broken expression.
+        let expr = self
+            .call()
+            .unwrap_or_else(|_| Expr::Literal(Box::new(LiteralExpr::Nil)));
+
+        if self.match_tokens(&[TokenType::PlusPlus, TokenType::MinusMinus]) {
+            let operator = self.previous().to_static();
+            let one = Expr::Literal(Box::new(LiteralExpr::Num(1.0)));
+            return self.update(expr, operator, one, true);
+        }
+
+        expr
+    }
+
+    pub fn call(&mut self) -> Result<Expr, String> {
+        let mut expr = self.primary()?;
+
+        loop {
+            if self.match_tokens(&[TokenType::LeftParen]) {
+                expr = self.finish_call(expr)?;
+            } else if self.match_tokens(&[TokenType::Dot]) {
+                let name = self
+                    .consume(TokenType::Identifier, "Expect property name after '.'.")?
+                    .to_static();
+                expr = Expr::Get(Box::new(GetExpr::new(expr, name)));
+            } else if self.match_tokens(&[TokenType::LeftBracket]) {
+                let bracket = self.previous().to_static();
+                let index = self.expression();
+                self.consume(TokenType::RightBracket, "Expect ']' after index.")?;
+                expr = Expr::Index(Box::new(IndexExpr::new(expr, bracket, index)));
+            } else {
+                break;
+            }
+        }
+
+        Ok(expr)
+    }
+
+    pub fn finish_call(&mut self, callee: Expr) -> Result<Expr, String> {
+        let mut arguments = Vec::new();
+
+        if !self.check(TokenType::RightParen) {
+            loop {
+                arguments.push(self.assignment());
+                if !self.match_tokens(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+
+        let paren = self
+            .consume(TokenType::RightParen, "Expect ')' after arguments.")?
+            .to_static();
+
+        Ok(Expr::Call(Box::new(CallExpr::new(
+            callee, paren, arguments,
+        ))))
+    }
+
+    /// Parses the rest of a list literal after its `[`. A trailing comma
+    /// before the `]` is allowed.
+    pub fn list(&mut self) -> Result<Expr, String> {
+        let mut elements = Vec::new();
+
+        while !self.check(TokenType::RightBracket) {
+            elements.push(self.assignment());
+            if !self.match_tokens(&[TokenType::Comma]) {
+                break;
+            }
+        }
+
+        self.consume(TokenType::RightBracket, "Expect ']' after list elements.")?;
+
+        Ok(Expr::List(Box::new(ListExpr::new(elements))))
+    }
+
+    /// Parses the rest of a map literal after its `{`. Braces only start a
+    /// map in expression position; `statement` rejects one at the start of
+    /// a statement, where they are reserved for blocks.
+    pub fn map(&mut self) -> Result<Expr, String> {
+        let brace = self.previous().to_static();
+        let mut entries = Vec::new();
+
+        while !self.check(TokenType::RightBrace) {
+            let key = self.assignment();
+            self.consume(TokenType::Colon, "Expect ':' after map key.")?;
+            let value = self.assignment();
+            entries.push((key, value));
+
+            if !self.match_tokens(&[TokenType::Comma]) {
+                break;
+            }
+        }
+
+        self.consume(TokenType::RightBrace, "Expect '}' after map entries.")?;
+
+        Ok(Expr::Map(Box::new(MapExpr::new(brace, entries))))
     }
 
     pub fn factor(&mut self) -> Expr {
         let mut expr = self.unary();
 
-        while self.match_tokens(&[TokenType::Slash, TokenType::Star]) {
-            let operator = self.previous().clone();
+        while self.match_tokens(&[
+            TokenType::Slash,
+            TokenType::Star,
+            TokenType::Percent,
+            TokenType::TildeSlash,
+        ]) {
+            let operator = self.previous().to_static();
             let right = self.unary();
             expr = Expr::Binary(Box::new(BinaryExpr {
                 left: Box::new(expr),
@@ -260,7 +745,7 @@ impl Parser {
         let mut expr = self.factor();
 
         while self.match_tokens(&[TokenType::Minus, TokenType::Plus]) {
-            let operator = self.previous().clone();
+            let operator = self.previous().to_static();
             let right = self.factor();
             expr = Expr::Binary(Box::new(BinaryExpr {
                 left: Box::new(expr),
@@ -273,7 +758,7 @@ impl Parser {
     }
 
     pub fn comparison(&mut self) -> Expr {
-        let mut expr = self.term();
+        let mut expr = self.shift();
 
         while self.match_tokens(&[
             TokenType::Greater,
@@ -281,7 +766,23 @@ impl Parser {
             TokenType::Less,
             TokenType::LessEqual,
         ]) {
-            let operator = self.previous().clone();
+            let operator = self.previous().to_static();
+            let right = self.shift();
+            expr = Expr::Binary(Box::new(BinaryExpr {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            }));
+        }
+
+        expr
+    }
+
+    pub fn shift(&mut self) -> Expr {
+        let mut expr = self.term();
+
+        while self.match_tokens(&[TokenType::LessLess, TokenType::GreaterGreater]) {
+            let operator = self.previous().to_static();
             let right = self.term();
             expr = Expr::Binary(Box::new(BinaryExpr {
                 left: Box::new(expr),
@@ -292,4 +793,34 @@ impl Parser {
 
         expr
     }
+
+    /// Parses the rest of an interpolated string after its first
+    /// `Interpolation` segment token.
+    pub fn interpolation(&mut self) -> Result<Expr, String> {
+        let mut parts = Vec::new();
+
+        loop {
+            let segment = self.previous().literal.clone().unwrap();
+            parts.push(Expr::Literal(Box::new(segment)));
+
+            if self.previous().token_type == TokenType::String {
+                break;
+            }
+
+            // The segment after `${}` follows straight on, with no
+            // expression in between.
+            if self.check(TokenType::String) || self.check(TokenType::Interpolation) {
+                let token = self.peek().clone();
+                let _ = self.error(&token, "Expect expression inside '${}'.");
+            } else {
+                parts.push(self.expression());
+            }
+
+            if !self.match_tokens(&[TokenType::Interpolation]) {
+                self.consume(TokenType::String, "Expect end of string interpolation.")?;
+            }
+        }
+
+        Ok(Expr::Interpolation(Box::new(InterpolationExpr { parts })))
+    }
 }
@@ -1,8 +1,12 @@
 use crate::ast::expr::Visitor;
-use crate::ast::expr::{BinaryExpr, Expr, GroupingExpr, LiteralExpr, UnaryExpr};
+use crate::ast::expr::{BinaryExpr, Expr, GroupingExpr, InterpolationExpr, LiteralExpr, UnaryExpr};
+use crate::ast::token::TokenType;
 
-use super::expr::VariableExpr;
-use super::stmt::{ExpressionStmt, PrintStmt, VarStmt};
+use super::expr::{
+    CallExpr, CommaExpr, ConditionalExpr, GetExpr, IndexExpr, IndexSetExpr, IndexUpdateExpr,
+    ListExpr, MapExpr, VariableExpr,
+};
+use super::stmt::{ExpressionStmt, MatchStmt, PrintStmt, VarStmt};
 
 pub struct Printer;
 
@@ -29,7 +33,7 @@ impl Printer {
 
 impl Visitor<String> for Printer {
     fn visit_binary_expr(&mut self, expr: &BinaryExpr) -> String {
-        self.parenthesize(&expr.operator.lexeme, &[&expr.left, &expr.right])
+        self.parenthesize(expr.operator.lexeme, &[&expr.left, &expr.right])
     }
 
     fn visit_grouping_expr(&mut self, expr: &GroupingExpr) -> String {
@@ -38,7 +42,7 @@ impl Visitor<String> for Printer {
 
     fn visit_literal_expr(&mut self, expr: &LiteralExpr) -> String {
         match expr {
-            LiteralExpr::Str(s) => s.clone(),
+            LiteralExpr::Str(s) => s.to_string(),
             LiteralExpr::Num(n) => n.to_string(),
             LiteralExpr::Bool(b) => b.to_string(),
             LiteralExpr::Nil => "nil".to_string(),
@@ -46,22 +50,88 @@ impl Visitor<String> for Printer {
     }
 
     fn visit_unary_expr(&mut self, expr: &UnaryExpr) -> String {
-        self.parenthesize(&expr.operator.lexeme, &[&expr.right])
+        self.parenthesize(expr.operator.lexeme, &[&expr.right])
     }
 
-    fn visit_expression_stmt(&mut self, stmt: &ExpressionStmt) -> String {
+    fn visit_expression_stmt(&mut self, _stmt: &ExpressionStmt) -> String {
         todo!()
     }
 
-    fn visit_print_stmt(&mut self, stmt: &PrintStmt) -> String {
+    fn visit_print_stmt(&mut self, _stmt: &PrintStmt) -> String {
         todo!()
     }
 
-    fn visit_var_stmt(&mut self, stmt: &VarStmt) -> String {
+    fn visit_var_stmt(&mut self, _stmt: &VarStmt) -> String {
         todo!()
     }
 
-    fn visit_variable_expr(&mut self, expr: &VariableExpr) -> String {
+    fn visit_match_stmt(&mut self, _stmt: &MatchStmt) -> String {
         todo!()
     }
+
+    fn visit_variable_expr(&mut self, _expr: &VariableExpr) -> String {
+        todo!()
+    }
+
+    fn visit_interpolation_expr(&mut self, expr: &InterpolationExpr) -> String {
+        let parts: Vec<&Expr> = expr.parts.iter().collect();
+        self.parenthesize("interpolate", &parts)
+    }
+
+    fn visit_list_expr(&mut self, expr: &ListExpr) -> String {
+        let elements: Vec<&Expr> = expr.elements.iter().collect();
+        self.parenthesize("list", &elements)
+    }
+
+    fn visit_index_expr(&mut self, expr: &IndexExpr) -> String {
+        self.parenthesize("index", &[&expr.object, &expr.index])
+    }
+
+    fn visit_index_set_expr(&mut self, expr: &IndexSetExpr) -> String {
+        self.parenthesize("index=", &[&expr.object, &expr.index, &expr.value])
+    }
+
+    fn visit_index_update_expr(&mut self, expr: &IndexUpdateExpr) -> String {
+        let target = self.parenthesize("index", &[&expr.object, &expr.index]);
+        let operator = &expr.operator.lexeme;
+
+        match expr.operator.token_type {
+            TokenType::PlusPlus | TokenType::MinusMinus if expr.postfix => {
+                format!("(post{} {})", operator, target)
+            }
+            TokenType::PlusPlus | TokenType::MinusMinus => format!("({} {})", operator, target),
+            _ => format!("({} {} {})", operator, target, self.print(&expr.value)),
+        }
+    }
+
+    fn visit_get_expr(&mut self, expr: &GetExpr) -> String {
+        let object = expr.object.accept(self);
+        format!("(. {} {})", object, expr.name.lexeme)
+    }
+
+    fn visit_call_expr(&mut self, expr: &CallExpr) -> String {
+        let mut exprs: Vec<&Expr> = vec![&expr.callee];
+        exprs.extend(expr.arguments.iter());
+        self.parenthesize("call", &exprs)
+    }
+
+    fn visit_conditional_expr(&mut self, expr: &ConditionalExpr) -> String {
+        self.parenthesize(
+            "?:",
+            &[&expr.condition, &expr.then_branch, &expr.else_branch],
+        )
+    }
+
+    fn visit_comma_expr(&mut self, expr: &CommaExpr) -> String {
+        self.parenthesize(",", &[&expr.left, &expr.right])
+    }
+
+    fn visit_map_expr(&mut self, expr: &MapExpr) -> String {
+        let exprs: Vec<&Expr> = expr
+            .entries
+            .iter()
+            .flat_map(|(key, value)| [key, value])
+            .collect();
+        self.parenthesize("map", &exprs)
+    }
 }
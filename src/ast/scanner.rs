@@ -1,14 +1,22 @@
 use lazy_static::lazy_static;
 use std::cell::Cell;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use unicode_xid::UnicodeXID;
 
 use crate::ast::expr::LiteralExpr;
+use crate::ast::interner::Symbol;
 use crate::ast::token::{Token, TokenType};
 
 pub struct ScanError {
     detected: Cell<bool>,
 }
 
+impl Default for ScanError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ScanError {
     pub fn new() -> Self {
         Self {
@@ -40,6 +48,7 @@ lazy_static! {
         m.insert("for".to_string(), TokenType::For);
         m.insert("fun".to_string(), TokenType::Fun);
         m.insert("if".to_string(), TokenType::If);
+        m.insert("match".to_string(), TokenType::Match);
         m.insert("nil".to_string(), TokenType::Nil);
         m.insert("or".to_string(), TokenType::Or);
         m.insert("print".to_string(), TokenType::Print);
@@ -53,38 +62,41 @@ lazy_static! {
     };
 }
 
-pub struct Scanner {
+/// Scans tokens on demand from borrowed source text. Tokens produced by
+/// `scan_token` wait in `tokens` until the iterator hands them out, and the
+/// final `Eof` token is yielded exactly once.
+///
+/// `interpolations` holds one entry per open `${` with the number of
+/// unclosed `{` seen inside it, so the matching `}` resumes the string.
+pub struct Scanner<'src> {
     pub keywords: &'static HashMap<String, TokenType>,
-    pub source: String,
-    pub tokens: Vec<Token>,
+    pub source: &'src str,
+    pub tokens: VecDeque<Token<'src>>,
     pub start: usize,
     pub current: usize,
     pub line: usize,
     pub error: ScanError,
+    pub finished: bool,
+    pub interpolations: Vec<usize>,
 }
 
-impl Scanner {
-    pub fn new(source: String, error: ScanError) -> Self {
+impl<'src> Scanner<'src> {
+    pub fn new(source: &'src str, error: ScanError) -> Self {
         Self {
             keywords: &KEYWORDS,
             source,
-            tokens: Vec::new(),
+            tokens: VecDeque::new(),
             start: 0,
             current: 0,
             line: 1,
             error,
+            finished: false,
+            interpolations: Vec::new(),
         }
     }
 
-    pub fn scan_tokens(&mut self) -> Vec<Token> {
-        while !self.is_at_end() {
-            self.start = self.current;
-            self.scan_token();
-        }
-
-        self.tokens
-            .push(Token::new(TokenType::Eof, "".to_string(), None, self.line));
-        self.tokens.clone()
+    pub fn scan_tokens(&mut self) -> Vec<Token<'src>> {
+        self.by_ref().collect()
     }
 
     pub fn is_at_end(&self) -> bool {
@@ -99,21 +111,89 @@ impl Scanner {
 
             ')' => self.add_token(TokenType::RightParen),
 
-            '{' => self.add_token(TokenType::LeftBrace),
+            '{' => {
+                if let Some(depth) = self.interpolations.last_mut() {
+                    *depth += 1;
+                }
+                self.add_token(TokenType::LeftBrace);
+            }
 
-            '}' => self.add_token(TokenType::RightBrace),
+            '}' => match self.interpolations.last_mut() {
+                Some(0) => {
+                    self.interpolations.pop();
+                    self.string();
+                }
+                Some(depth) => {
+                    *depth -= 1;
+                    self.add_token(TokenType::RightBrace);
+                }
+                None => self.add_token(TokenType::RightBrace),
+            },
+
+            '[' => self.add_token(TokenType::LeftBracket),
+
+            ']' => self.add_token(TokenType::RightBracket),
+
+            ':' => self.add_token(TokenType::Colon),
+
+            '?' => self.add_token(TokenType::Question),
 
             ',' => self.add_token(TokenType::Comma),
 
             '.' => self.add_token(TokenType::Dot),
 
-            '-' => self.add_token(TokenType::Minus),
+            '-' => {
+                let token_type = if self.match_char('-') {
+                    TokenType::MinusMinus
+                } else if self.match_char('=') {
+                    TokenType::MinusEqual
+                } else {
+                    TokenType::Minus
+                };
+                self.add_token(token_type);
+            }
 
-            '+' => self.add_token(TokenType::Plus),
+            '+' => {
+                let token_type = if self.match_char('+') {
+                    TokenType::PlusPlus
+                } else if self.match_char('=') {
+                    TokenType::PlusEqual
+                } else {
+                    TokenType::Plus
+                };
+                self.add_token(token_type);
+            }
 
             ';' => self.add_token(TokenType::Semicolon),
 
-            '*' => self.add_token(TokenType::Star),
+            '*' => {
+                let token_type = if self.match_char('*') {
+                    TokenType::StarStar
+                } else if self.match_char('=') {
+                    TokenType::StarEqual
+                } else {
+                    TokenType::Star
+                };
+                self.add_token(token_type);
+            }
+
+            '%' => self.add_token(TokenType::Percent),
+
+            '&' => self.add_token(TokenType::Ampersand),
+
+            '|' => self.add_token(TokenType::Pipe),
+
+            '^' => self.add_token(TokenType::Caret),
+
+            // `//` already starts a comment, so floor division is spelled `~/`.
+            '~' => {
+                let token_type = if self.match_char('/') {
+                    TokenType::TildeSlash
+                } else {
+                    TokenType::Tilde
+                };
+                self.add_token(token_type);
+            }
 
             '!' => {
                 let token_type = if self.match_char('=') {
@@ -127,6 +207,8 @@ impl Scanner {
             '=' => {
                 let token_type = if self.match_char('=') {
                     TokenType::EqualEqual
+                } else if self.match_char('>') {
+                    TokenType::FatArrow
                 } else {
                     TokenType::Equal
                 };
@@ -136,6 +218,8 @@ impl Scanner {
             '<' => {
                 let token_type = if self.match_char('=') {
                     TokenType::LessEqual
+                } else if self.match_char('<') {
+                    TokenType::LessLess
                 } else {
                     TokenType::Less
                 };
@@ -145,6 +229,8 @@ impl Scanner {
             '>' => {
                 let token_type = if self.match_char('=') {
                     TokenType::GreaterEqual
+                } else if self.match_char('>') {
+                    TokenType::GreaterGreater
                 } else {
                     TokenType::Greater
                 };
@@ -156,6 +242,10 @@ impl Scanner {
                     while self.peek() != '\n' && !self.is_at_end() {
                         self.advance();
                     }
+                } else if self.match_char('*') {
+                    self.block_comment();
+                } else if self.match_char('=') {
+                    self.add_token(TokenType::SlashEqual);
                 } else {
                     self.add_token(TokenType::Slash);
                 }
@@ -171,6 +261,8 @@ impl Scanner {
 
             '"' => self.string(),
 
+            'r' if matches!(self.peek(), '"' | '#') => self.raw_string(),
+
             _ => {
                 if self.is_digit(c) {
                     self.number();
@@ -183,12 +275,40 @@ impl Scanner {
         }
     }
 
+    /// Skips a `/* ... */` comment after its opening `/*`. Comments nest, so
+    /// each inner `/*` needs its own `*/`.
+    pub fn block_comment(&mut self) {
+        let opened_on = self.line;
+        let mut depth = 1;
+
+        while depth > 0 {
+            if self.is_at_end() {
+                self.error.error(opened_on, "Unterminated block comment.");
+                return;
+            }
+
+            if self.match_char('/') {
+                if self.match_char('*') {
+                    depth += 1;
+                }
+            } else if self.match_char('*') {
+                if self.match_char('/') {
+                    depth -= 1;
+                }
+            } else if self.advance() == '\n' {
+                self.line += 1;
+            }
+        }
+    }
+
+    /// Identifiers follow Unicode UAX #31: they start with an `XID_Start`
+    /// character or `_` and continue with `XID_Continue` characters.
     pub fn is_alpha(&self, c: char) -> bool {
-        matches!(c, 'a'..='z' | 'A'..='Z' | '_')
+        c == '_' || c.is_xid_start()
     }
 
     pub fn is_alpha_numeric(&self, c: char) -> bool {
-        c.is_alphanumeric() || c == '_'
+        c.is_xid_continue()
     }
 
     pub fn identifier(&mut self) {
@@ -207,38 +327,163 @@ impl Scanner {
     }
 
     pub fn number(&mut self) {
-        while self.peek().is_digit(10) {
-            self.advance();
+        if &self.source[self.start..self.current] == "0" {
+            let radix = match self.peek() {
+                'x' | 'X' => Some((16, "hexadecimal")),
+                'b' | 'B' => Some((2, "binary")),
+                'o' | 'O' => Some((8, "octal")),
+                _ => None,
+            };
+
+            if let Some((radix, name)) = radix {
+                self.advance();
+                self.radix_number(radix, name);
+                return;
+            }
         }
 
+        self.digits(10);
+
         // Look for a fractional part.
-        if self.peek() == '.' && self.peek_next().is_digit(10) {
+        if self.peek() == '.' && self.peek_next().is_ascii_digit() {
             // Consume the "."
             self.advance();
+            self.digits(10);
+        }
+
+        // Look for an exponent.
+        if matches!(self.peek(), 'e' | 'E') {
+            self.advance();
+
+            if matches!(self.peek(), '+' | '-') {
+                self.advance();
+            }
+
+            if !self.peek().is_ascii_digit() {
+                self.error.error(self.line, "Expect digits in exponent.");
+                return;
+            }
 
-            while self.peek().is_digit(10) {
+            self.digits(10);
+        }
+
+        let Some(text) = self.number_text(10) else {
+            return;
+        };
+
+        match text.parse::<f64>() {
+            Ok(value) => {
+                self.add_token_with_literal(TokenType::Number, Some(LiteralExpr::Num(value)))
+            }
+            Err(_) => self.error.error(self.line, "Invalid number literal."),
+        }
+    }
+
+    fn radix_number(&mut self, radix: u32, name: &str) {
+        let prefix = self.source[self.start..self.current].to_string();
+
+        let count = self.digits(radix);
+
+        if self.is_alpha_numeric(self.peek()) {
+            let message = format!("Invalid digit '{}' in {} literal.", self.peek(), name);
+            self.error.error(self.line, &message);
+
+            while self.is_alpha_numeric(self.peek()) {
                 self.advance();
             }
+            return;
+        }
+
+        if count == 0 {
+            let message = format!("Expect {} digits after '{}'.", name, prefix);
+            self.error.error(self.line, &message);
+            return;
         }
 
-        let value: f64 = self.source[self.start..self.current].parse().unwrap();
-        self.add_token_with_literal(TokenType::Number, Some(LiteralExpr::Num(value)));
+        let Some(text) = self.number_text(radix) else {
+            return;
+        };
+
+        match u64::from_str_radix(&text[prefix.len()..], radix) {
+            Ok(value) => {
+                self.add_token_with_literal(TokenType::Number, Some(LiteralExpr::Num(value as f64)))
+            }
+            Err(_) => self.error.error(self.line, "Number literal is too large."),
+        }
+    }
+
+    /// Consumes a run of digits in `radix` along with any `_` separators and
+    /// returns how many digits were seen.
+    fn digits(&mut self, radix: u32) -> usize {
+        let mut count = 0;
+
+        while self.peek().is_digit(radix) || self.peek() == '_' {
+            if self.advance() != '_' {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Returns the current lexeme with separators removed, or reports an
+    /// error if a separator is not surrounded by digits.
+    fn number_text(&mut self, radix: u32) -> Option<String> {
+        let lexeme: Vec<char> = self.source[self.start..self.current].chars().collect();
+
+        for (i, c) in lexeme.iter().enumerate() {
+            if *c != '_' {
+                continue;
+            }
+
+            let before = i > 0 && lexeme[i - 1].is_digit(radix);
+            let after = lexeme.get(i + 1).is_some_and(|next| next.is_digit(radix));
+
+            if !before || !after {
+                self.error
+                    .error(self.line, "Digit separator '_' must be between digits.");
+                return None;
+            }
+        }
+
+        Some(lexeme.into_iter().filter(|c| *c != '_').collect())
     }
 
     pub fn is_digit(&self, c: char) -> bool {
-        c.is_digit(10)
+        c.is_ascii_digit()
     }
 
+    /// Scans the rest of a string literal after its opening quote, decoding
+    /// escape sequences into the literal value. A `${` ends the current
+    /// segment with an `Interpolation` token; scanning resumes here after the
+    /// matching `}`, and the last segment becomes a plain `String` token.
     pub fn string(&mut self) {
-        if !self.is_at_end() {
-            self.advance();
-        }
+        let mut value = String::new();
 
         while self.peek() != '"' && !self.is_at_end() {
-            if self.peek() == '\n' {
-                self.line += 1;
+            let c = self.advance();
+
+            match c {
+                '\n' => {
+                    self.line += 1;
+                    value.push(c);
+                }
+                '$' if self.match_char('{') => {
+                    self.interpolations.push(0);
+                    let value = Symbol::intern(&value);
+                    self.add_token_with_literal(
+                        TokenType::Interpolation,
+                        Some(LiteralExpr::Str(value)),
+                    );
+                    return;
+                }
+                '\\' => {
+                    if let Some(escaped) = self.escape_sequence() {
+                        value.push(escaped);
+                    }
+                }
+                _ => value.push(c),
             }
-            self.advance();
         }
 
         if self.is_at_end() {
@@ -246,38 +491,126 @@ impl Scanner {
             return;
         }
 
+        // The closing ".
         self.advance();
 
-        let value = self.source[self.start + 1..self.current - 1].to_string();
+        let value = Symbol::intern(&value);
         self.add_token_with_literal(TokenType::String, Some(LiteralExpr::Str(value)));
     }
 
-    pub fn peek(&self) -> char {
+    fn escape_sequence(&mut self) -> Option<char> {
         if self.is_at_end() {
-            '\0'
-        } else {
-            self.source[self.current..].chars().next().unwrap()
+            return None;
+        }
+
+        match self.advance() {
+            'n' => Some('\n'),
+            't' => Some('\t'),
+            'r' => Some('\r'),
+            '0' => Some('\0'),
+            '"' => Some('"'),
+            '\\' => Some('\\'),
+            '$' => Some('$'),
+            'u' => self.unicode_escape(),
+            c => {
+                if c == '\n' {
+                    self.line += 1;
+                }
+                let message = format!("Unknown escape sequence '\\{}'.", c.escape_default());
+                self.error.error(self.line, &message);
+                None
+            }
         }
     }
 
-    pub fn peek_next(&self) -> char {
-        if self.current + 1 >= self.source.len() {
-            '\0'
+    fn unicode_escape(&mut self) -> Option<char> {
+        if !self.match_char('{') {
+            self.error.error(self.line, "Expect '{' after '\\u'.");
+            return None;
+        }
+
+        let begin = self.current;
+        while self.peek().is_ascii_hexdigit() {
+            self.advance();
+        }
+        let digits = &self.source[begin..self.current];
+
+        if !self.match_char('}') {
+            self.error
+                .error(self.line, "Expect '}' after unicode escape digits.");
+            return None;
+        }
+
+        let scalar = if digits.is_empty() || digits.len() > 6 {
+            None
         } else {
-            self.source[self.current + 1..].chars().next().unwrap()
+            u32::from_str_radix(digits, 16)
+                .ok()
+                .and_then(char::from_u32)
+        };
+
+        if scalar.is_none() {
+            let message = format!("Invalid unicode escape '\\u{{{}}}'.", digits);
+            self.error.error(self.line, &message);
         }
+
+        scalar
     }
 
-    pub fn match_char(&mut self, expected: char) -> bool {
-        if self.is_at_end() {
-            return false;
+    /// Scans a raw string such as `r"C:\path"` or `r#"say "hi""#` after its
+    /// leading `r`. Backslashes are kept as written and the closing quote must
+    /// be followed by as many `#` as the opening one.
+    pub fn raw_string(&mut self) {
+        let mut hashes = 0;
+        while self.match_char('#') {
+            hashes += 1;
         }
-        let current_char = self.source[self.current..].chars().next().unwrap();
-        if current_char != expected {
+
+        if !self.match_char('"') {
+            self.error
+                .error(self.line, "Expect '\"' to start raw string.");
+            return;
+        }
+
+        let begin = self.current;
+        let closing = format!("\"{}", "#".repeat(hashes));
+
+        while !self.remaining().starts_with(&closing) {
+            if self.is_at_end() {
+                self.error.error(self.line, "Unterminated raw string.");
+                return;
+            }
+
+            if self.advance() == '\n' {
+                self.line += 1;
+            }
+        }
+
+        let value = Symbol::intern(&self.source[begin..self.current]);
+        self.current += closing.len();
+        self.add_token_with_literal(TokenType::String, Some(LiteralExpr::Str(value)));
+    }
+
+    pub fn peek(&self) -> char {
+        self.remaining().chars().next().unwrap_or('\0')
+    }
+
+    pub fn peek_next(&self) -> char {
+        self.remaining().chars().nth(1).unwrap_or('\0')
+    }
+
+    /// The unscanned rest of the source. `current` always sits on a char
+    /// boundary while scanning; positions past the end yield an empty slice.
+    fn remaining(&self) -> &'src str {
+        self.source.get(self.current..).unwrap_or("")
+    }
+
+    pub fn match_char(&mut self, expected: char) -> bool {
+        if self.is_at_end() || self.peek() != expected {
             return false;
         }
 
-        self.current += current_char.len_utf8();
+        self.current += expected.len_utf8();
         true
     }
 
@@ -295,6 +628,34 @@ impl Scanner {
     pub fn add_token_with_literal(&mut self, token_type: TokenType, literal: Option<LiteralExpr>) {
         let text = &self.source[self.start..self.current];
         self.tokens
-            .push(Token::new(token_type, text.to_string(), literal, self.line));
+            .push_back(Token::new(token_type, text, literal, self.line));
+    }
+}
+
+impl<'src> Iterator for Scanner<'src> {
+    type Item = Token<'src>;
+
+    fn next(&mut self) -> Option<Token<'src>> {
+        while self.tokens.is_empty() && !self.is_at_end() {
+            self.start = self.current;
+            self.scan_token();
+        }
+
+        if let Some(token) = self.tokens.pop_front() {
+            return Some(token);
+        }
+
+        if self.finished {
+            return None;
+        }
+
+        if !self.interpolations.is_empty() {
+            self.interpolations.clear();
+            self.error
+                .error(self.line, "Unterminated string interpolation.");
+        }
+
+        self.finished = true;
+        Some(Token::new(TokenType::Eof, "", None, self.line))
     }
 }
@@ -1,11 +1,12 @@
 use super::{
-    expr::{Expr, Visitor},
+    expr::{Expr, LiteralExpr, Visitor},
     token::Token,
 };
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Stmt {
     Expression(ExpressionStmt),
+    Match(MatchStmt),
     Print(PrintStmt),
     Var(VarStmt),
 }
@@ -15,11 +16,9 @@ impl Stmt {
         match self {
             // Stmt::Block(stmt) => visitor.visit_block_stmt(stmt),
             Stmt::Expression(stmt) => visitor.visit_expression_stmt(stmt),
+            Stmt::Match(stmt) => visitor.visit_match_stmt(stmt),
             Stmt::Print(stmt) => visitor.visit_print_stmt(stmt),
             Stmt::Var(stmt) => visitor.visit_var_stmt(stmt),
-            _ => {
-                panic!("Unhandled statement type")
-            }
         }
     }
 }
@@ -31,12 +30,49 @@ pub struct ExpressionStmt {
 
 impl ExpressionStmt {
     pub fn new(expression: Expr) -> Self {
+        Self { expression }
+    }
+}
+
+/// `match (subject) { pattern or pattern if guard => statement ... }`.
+/// The first arm whose pattern matches and whose guard is truthy runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchStmt {
+    pub subject: Expr,
+    pub arms: Vec<MatchArm>,
+}
+
+impl MatchStmt {
+    pub fn new(subject: Expr, arms: Vec<MatchArm>) -> Self {
+        Self { subject, arms }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm {
+    pub patterns: Vec<Pattern>,
+    pub guard: Option<Expr>,
+    pub body: Box<Stmt>,
+}
+
+impl MatchArm {
+    pub fn new(patterns: Vec<Pattern>, guard: Option<Expr>, body: Stmt) -> Self {
         Self {
-            expression: expression,
+            patterns,
+            guard,
+            body: Box::new(body),
         }
     }
 }
 
+/// A literal pattern matches values equal to it at runtime; `_` matches
+/// anything.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    Literal(LiteralExpr),
+    Wildcard,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct PrintStmt {
     pub expression: Expr,
@@ -44,23 +80,18 @@ pub struct PrintStmt {
 
 impl PrintStmt {
     pub fn new(expression: Expr) -> Self {
-        Self {
-            expression: expression,
-        }
+        Self { expression }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct VarStmt {
-    pub name: Token,
+    pub name: Token<'static>,
     pub initializer: Expr,
 }
 
 impl VarStmt {
-    pub fn new(name: Token, initializer: Expr) -> Self {
-        Self {
-            name: name,
-            initializer: initializer,
-        }
+    pub fn new(name: Token<'static>, initializer: Expr) -> Self {
+        Self { name, initializer }
     }
 }
@@ -0,0 +1,75 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::ast::interner::Symbol;
+
+/// A runtime string. Constants from the source stay interned, so comparing
+/// two of them only looks at the pointer. Strings built while the program
+/// runs are owned and kept out of the interner.
+#[derive(Debug, Clone)]
+pub enum Str {
+    Interned(Symbol),
+    Owned(Rc<str>),
+}
+
+impl Str {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Str::Interned(symbol) => symbol.as_str(),
+            Str::Owned(value) => value,
+        }
+    }
+}
+
+impl PartialEq for Str {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Str::Interned(a), Str::Interned(b)) => a == b,
+            _ => self.as_str() == other.as_str(),
+        }
+    }
+}
+
+impl Eq for Str {}
+
+/// Hashes the text rather than the pointer, so an owned string finds the
+/// map entry of an interned one with the same contents.
+impl Hash for Str {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl Deref for Str {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Symbol> for Str {
+    fn from(value: Symbol) -> Self {
+        Str::Interned(value)
+    }
+}
+
+impl From<&str> for Str {
+    fn from(value: &str) -> Self {
+        Str::Owned(value.into())
+    }
+}
+
+impl From<String> for Str {
+    fn from(value: String) -> Self {
+        Str::Owned(value.into())
+    }
+}
+
+impl fmt::Display for Str {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
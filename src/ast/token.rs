@@ -1,6 +1,7 @@
 use std::fmt;
 
 use crate::ast::expr::LiteralExpr;
+use crate::ast::interner::Symbol;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum TokenType {
@@ -9,6 +10,10 @@ pub enum TokenType {
     RightParen,
     LeftBrace,
     RightBrace,
+    LeftBracket,
+    RightBracket,
+    Colon,
+    Question,
     Comma,
     Dot,
     Minus,
@@ -16,6 +21,10 @@ pub enum TokenType {
     Semicolon,
     Slash,
     Star,
+    Percent,
+    Ampersand,
+    Pipe,
+    Caret,
 
     // One or two character tokens.
     Bang,
@@ -26,10 +35,23 @@ pub enum TokenType {
     GreaterEqual,
     Less,
     LessEqual,
+    LessLess,
+    GreaterGreater,
+    PlusEqual,
+    MinusEqual,
+    StarEqual,
+    SlashEqual,
+    PlusPlus,
+    MinusMinus,
+    FatArrow,
+    StarStar,
+    Tilde,
+    TildeSlash,
 
     // Literals.
     Identifier,
     String,
+    Interpolation,
     Number,
 
     // Keywords.
@@ -40,6 +62,7 @@ pub enum TokenType {
     Fun,
     For,
     If,
+    Match,
     Nil,
     Or,
     Print,
@@ -53,18 +76,21 @@ pub enum TokenType {
     Eof,
 }
 
+/// A token borrows its lexeme from the source it was scanned from. The
+/// parser turns the tokens it keeps in the AST into `Token<'static>` with
+/// [`Token::to_static`].
 #[derive(Debug, Clone, PartialEq)]
-pub struct Token {
+pub struct Token<'src> {
     pub token_type: TokenType,
-    pub lexeme: String,
+    pub lexeme: &'src str,
     pub literal: Option<LiteralExpr>,
     pub line: usize,
 }
 
-impl Token {
+impl<'src> Token<'src> {
     pub fn new(
         token_type: TokenType,
-        lexeme: String,
+        lexeme: &'src str,
         literal: Option<LiteralExpr>,
         line: usize,
     ) -> Self {
@@ -75,9 +101,97 @@ impl Token {
             line,
         }
     }
+
+    /// Detaches the token from its source. Operators, punctuation and
+    /// keywords always have the same text; identifiers and literals are
+    /// interned.
+    pub fn to_static(&self) -> Token<'static> {
+        let lexeme = match self.token_type.lexeme() {
+            Some(lexeme) => lexeme,
+            None => Symbol::intern(self.lexeme).as_str(),
+        };
+
+        Token {
+            token_type: self.token_type,
+            lexeme,
+            literal: self.literal.clone(),
+            line: self.line,
+        }
+    }
+}
+
+impl TokenType {
+    /// The text of a token type that is always spelled the same way.
+    pub fn lexeme(&self) -> Option<&'static str> {
+        let lexeme = match self {
+            TokenType::LeftParen => "(",
+            TokenType::RightParen => ")",
+            TokenType::LeftBrace => "{",
+            TokenType::RightBrace => "}",
+            TokenType::LeftBracket => "[",
+            TokenType::RightBracket => "]",
+            TokenType::Colon => ":",
+            TokenType::Question => "?",
+            TokenType::Comma => ",",
+            TokenType::Dot => ".",
+            TokenType::Minus => "-",
+            TokenType::Plus => "+",
+            TokenType::Semicolon => ";",
+            TokenType::Slash => "/",
+            TokenType::Star => "*",
+            TokenType::Percent => "%",
+            TokenType::Ampersand => "&",
+            TokenType::Pipe => "|",
+            TokenType::Caret => "^",
+            TokenType::Bang => "!",
+            TokenType::BangEqual => "!=",
+            TokenType::Equal => "=",
+            TokenType::EqualEqual => "==",
+            TokenType::Greater => ">",
+            TokenType::GreaterEqual => ">=",
+            TokenType::Less => "<",
+            TokenType::LessEqual => "<=",
+            TokenType::LessLess => "<<",
+            TokenType::GreaterGreater => ">>",
+            TokenType::PlusEqual => "+=",
+            TokenType::MinusEqual => "-=",
+            TokenType::StarEqual => "*=",
+            TokenType::SlashEqual => "/=",
+            TokenType::PlusPlus => "++",
+            TokenType::MinusMinus => "--",
+            TokenType::FatArrow => "=>",
+            TokenType::StarStar => "**",
+            TokenType::Tilde => "~",
+            TokenType::TildeSlash => "~/",
+            TokenType::And => "and",
+            TokenType::Class => "class",
+            TokenType::Else => "else",
+            TokenType::False => "false",
+            TokenType::Fun => "fun",
+            TokenType::For => "for",
+            TokenType::If => "if",
+            TokenType::Match => "match",
+            TokenType::Nil => "nil",
+            TokenType::Or => "or",
+            TokenType::Print => "print",
+            TokenType::Return => "return",
+            TokenType::Super => "super",
+            TokenType::This => "this",
+            TokenType::True => "true",
+            TokenType::Var => "var",
+            TokenType::While => "while",
+            TokenType::Eof => "",
+            TokenType::Identifier
+            | TokenType::String
+            | TokenType::Interpolation
+            | TokenType::Number => return None,
+        };
+
+        Some(lexeme)
+    }
 }
 
-impl fmt::Display for Token {
+impl fmt::Display for Token<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{} {} {:?}", self.token_type, self.lexeme, self.literal)
     }
@@ -1,36 +1,51 @@
 use core::panic;
+use std::rc::Rc;
 
-use crate::ast::expr::{BinaryExpr, Expr, GroupingExpr, LiteralExpr, UnaryExpr};
+use crate::ast::callable::Callable;
+use crate::ast::expr::{BinaryExpr, Expr, GroupingExpr, InterpolationExpr, LiteralExpr, UnaryExpr};
+use crate::ast::expr::{CallExpr, CommaExpr, ConditionalExpr, GetExpr, IndexExpr, IndexSetExpr};
+use crate::ast::expr::{IndexUpdateExpr, ListExpr, MapExpr};
 use crate::ast::expr::{VariableExpr, Visitor};
+use crate::ast::list::{List, ListMethod};
+use crate::ast::map::{HashKey, Map, MapMethod};
 use crate::ast::object::Object;
-use crate::ast::stmt::{ExpressionStmt, PrintStmt, Stmt, VarStmt};
+use crate::ast::stmt::{ExpressionStmt, MatchStmt, Pattern, PrintStmt, Stmt, VarStmt};
+use crate::ast::string::Str;
 use crate::ast::token::{Token, TokenType};
 
+#[derive(Debug)]
 pub struct RuntimeError {
-    pub token: Token,
+    pub token: Token<'static>,
     pub message: String,
 }
 
 impl RuntimeError {
-    pub fn new(token: Token, message: String) -> Self {
+    pub fn new(token: Token<'static>, message: String) -> Self {
         Self { token, message }
     }
 }
 
+/// The largest integer an f64 holds exactly, `2^53 - 1`. Bitwise operators
+/// only accept integers within this range.
+pub const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
 pub struct Interpreter;
 
 impl Interpreter {
     pub fn interpret(&mut self, statements: Vec<Stmt>) {
-        statements.iter().for_each(|stmt| {
-            self.execute(stmt.clone());
-        });
+        for stmt in statements {
+            if let Err(error) = self.execute(stmt) {
+                eprintln!("{}\n[line {}]", error.message, error.token.line);
+                return;
+            }
+        }
     }
 
-    pub fn execute(&mut self, stmt: Stmt) {
-        stmt.accept(self);
+    pub fn execute(&mut self, stmt: Stmt) -> Result<Object, RuntimeError> {
+        stmt.accept(self)
     }
 
-    pub fn evaluate(&mut self, expr: Expr) -> Object {
+    pub fn evaluate(&mut self, expr: Expr) -> Result<Object, RuntimeError> {
         expr.accept(self)
     }
 
@@ -43,7 +58,7 @@ impl Interpreter {
             return *boolean;
         }
 
-        if let Some(string) = object.get_value::<String>() {
+        if let Some(string) = object.get_value::<Str>() {
             return !string.is_empty();
         }
 
@@ -55,198 +70,522 @@ impl Interpreter {
     }
 
     pub fn is_equal(&self, a: &Object, b: &Object) -> bool {
+        if a.is::<Option<()>>() && b.is::<Option<()>>() {
+            return true;
+        }
+
         if a.is::<f64>() && b.is::<f64>() {
             return a.get_value::<f64>() == b.get_value::<f64>();
         }
 
-        if a.is::<String>() && b.is::<String>() {
-            return a.get_value::<String>() == b.get_value::<String>();
+        // Two string constants compare by pointer; see `Str`.
+        if a.is::<Str>() && b.is::<Str>() {
+            return a.get_value::<Str>() == b.get_value::<Str>();
         }
 
         if a.is::<bool>() && b.is::<bool>() {
             return a.get_value::<bool>() == b.get_value::<bool>();
         }
 
+        if let (Some(a), Some(b)) = (a.get_value::<Rc<List>>(), b.get_value::<Rc<List>>()) {
+            return Rc::ptr_eq(a, b);
+        }
+
+        if let (Some(a), Some(b)) = (a.get_value::<Rc<Map>>(), b.get_value::<Rc<Map>>()) {
+            return Rc::ptr_eq(a, b);
+        }
+
         false
     }
-}
 
-impl Visitor<Object> for Interpreter {
-    fn visit_literal_expr(&mut self, expr: &LiteralExpr) -> Object {
-        match expr {
-            LiteralExpr::Str(value) => Object::new(value.clone()),
-            LiteralExpr::Num(value) => Object::new(*value),
-            LiteralExpr::Bool(value) => Object::new(*value),
-            _ => {
-                panic!("Unhandled literal expression type");
-            }
+    fn number_operands(
+        &self,
+        operator: &Token<'static>,
+        left: &Object,
+        right: &Object,
+    ) -> Result<(f64, f64), RuntimeError> {
+        if let (Some(left), Some(right)) = (left.get_value::<f64>(), right.get_value::<f64>()) {
+            Ok((*left, *right))
+        } else {
+            Err(RuntimeError::new(
+                operator.clone(),
+                "Operands must be numbers.".to_string(),
+            ))
         }
     }
 
-    fn visit_binary_expr(&mut self, expr: &BinaryExpr) -> Object {
-        let left = self.evaluate(Expr::from(*(expr.left).clone()));
-        let right = self.evaluate(Expr::from(*(expr.right).clone()));
-
-        match expr.operator.token_type {
+    /// Applies a binary operator. `token_type` may differ from `operator`'s
+    /// own type when a compound assignment such as `+=` borrows the
+    /// arithmetic of `+`; errors are still reported at `operator`.
+    fn binary_operation(
+        &self,
+        operator: &Token<'static>,
+        token_type: TokenType,
+        left: &Object,
+        right: &Object,
+    ) -> Result<Object, RuntimeError> {
+        match token_type {
             TokenType::EqualEqual => {
-                let value = self.is_equal(&left, &right);
-                Object::new(value)
+                let value = self.is_equal(left, right);
+                Ok(Object::new(value))
             }
             TokenType::BangEqual => {
-                let value = self.is_equal(&left, &right);
-                Object::new(!value)
+                let value = self.is_equal(left, right);
+                Ok(Object::new(!value))
             }
             TokenType::Greater => {
-                if let (Some(left_num), Some(right_num)) =
-                    (left.get_value::<f64>(), right.get_value::<f64>())
-                {
-                    Object::new(left_num > right_num)
-                } else {
-                    panic!(
-                        "Operands must be numbers for the {:?} operation",
-                        expr.operator.token_type
-                    );
-                }
+                let (left, right) = self.number_operands(operator, left, right)?;
+                Ok(Object::new(left > right))
             }
             TokenType::GreaterEqual => {
-                if let (Some(left_num), Some(right_num)) =
-                    (left.get_value::<f64>(), right.get_value::<f64>())
-                {
-                    Object::new(left_num >= right_num)
-                } else {
-                    panic!(
-                        "Operands must be numbers for the {:?} operation",
-                        expr.operator.token_type
-                    );
-                }
+                let (left, right) = self.number_operands(operator, left, right)?;
+                Ok(Object::new(left >= right))
             }
             TokenType::Less => {
-                if let (Some(left_num), Some(right_num)) =
-                    (left.get_value::<f64>(), right.get_value::<f64>())
-                {
-                    Object::new(left_num < right_num)
-                } else {
-                    panic!(
-                        "Operands must be numbers for the {:?} operation",
-                        expr.operator.token_type
-                    );
-                }
+                let (left, right) = self.number_operands(operator, left, right)?;
+                Ok(Object::new(left < right))
             }
             TokenType::LessEqual => {
-                if let (Some(left_num), Some(right_num)) =
-                    (left.get_value::<f64>(), right.get_value::<f64>())
-                {
-                    Object::new(left_num <= right_num)
-                } else {
-                    panic!(
-                        "Operands must be numbers for the {:?} operation",
-                        expr.operator.token_type
-                    );
-                }
+                let (left, right) = self.number_operands(operator, left, right)?;
+                Ok(Object::new(left <= right))
             }
             TokenType::Plus => {
                 if let (Some(left_num), Some(right_num)) =
                     (left.get_value::<f64>(), right.get_value::<f64>())
                 {
-                    Object::new(left_num + right_num)
+                    Ok(Object::new(left_num + right_num))
                 } else if let (Some(left_str), Some(right_str)) =
-                    (left.get_value::<String>(), right.get_value::<String>())
+                    (left.get_value::<Str>(), right.get_value::<Str>())
                 {
-                    Object::new(format!("{}{}", left_str, right_str))
+                    let value = Str::from(format!("{}{}", left_str, right_str));
+                    Ok(Object::new(value))
                 } else {
-                    panic!(
-                        "Operands {:?}, {:?} must be matching types for the {:?} operation",
-                        left.get_value::<String>(),
-                        right.get_value::<String>(),
-                        expr.operator.token_type
-                    );
+                    Err(RuntimeError::new(
+                        operator.clone(),
+                        "Operands must be two numbers or two strings.".to_string(),
+                    ))
                 }
             }
             TokenType::Minus => {
-                if let (Some(left_num), Some(right_num)) =
-                    (left.get_value::<f64>(), right.get_value::<f64>())
-                {
-                    Object::new(left_num - right_num)
-                } else {
-                    panic!(
-                        "Operands must be numbers for the {:?} operation",
-                        expr.operator.token_type
-                    );
-                }
+                let (left, right) = self.number_operands(operator, left, right)?;
+                Ok(Object::new(left - right))
             }
             TokenType::Slash => {
-                if let (Some(left_num), Some(right_num)) =
-                    (left.get_value::<f64>(), right.get_value::<f64>())
-                {
-                    Object::new(left_num / right_num)
-                } else {
-                    panic!(
-                        "Operands must be numbers for the {:?} operation",
-                        expr.operator.token_type
-                    );
-                }
+                let (left, right) = self.number_operands(operator, left, right)?;
+                Ok(Object::new(left / right))
             }
             TokenType::Star => {
-                if let (Some(left_num), Some(right_num)) =
-                    (left.get_value::<f64>(), right.get_value::<f64>())
-                {
-                    Object::new(left_num * right_num)
+                let (left, right) = self.number_operands(operator, left, right)?;
+                Ok(Object::new(left * right))
+            }
+            TokenType::Percent => {
+                let (left, right) = self.number_operands(operator, left, right)?;
+                self.check_divisor(operator, right)?;
+                // Floored modulo: the result takes the sign of the divisor.
+                let remainder = left % right;
+                if remainder != 0.0 && (remainder < 0.0) != (right < 0.0) {
+                    Ok(Object::new(remainder + right))
                 } else {
-                    panic!(
-                        "Operands must be numbers for the {:?} operation",
-                        expr.operator.token_type
-                    );
+                    Ok(Object::new(remainder))
                 }
             }
+            TokenType::TildeSlash => {
+                let (left, right) = self.number_operands(operator, left, right)?;
+                self.check_divisor(operator, right)?;
+                Ok(Object::new((left / right).floor()))
+            }
+            TokenType::StarStar => {
+                let (left, right) = self.number_operands(operator, left, right)?;
+                Ok(Object::new(left.powf(right)))
+            }
+            TokenType::Ampersand => {
+                let (left, right) = self.integer_operands(operator, left, right)?;
+                Ok(Object::new((left & right) as f64))
+            }
+            TokenType::Pipe => {
+                let (left, right) = self.integer_operands(operator, left, right)?;
+                Ok(Object::new((left | right) as f64))
+            }
+            TokenType::Caret => {
+                let (left, right) = self.integer_operands(operator, left, right)?;
+                Ok(Object::new((left ^ right) as f64))
+            }
+            TokenType::LessLess => {
+                let (left, right) = self.integer_operands(operator, left, right)?;
+                let amount = self.shift_amount(operator, right)?;
+                // Shifting a 53-bit value by at most 64 can't overflow an i128.
+                let result = (left as i128) << amount;
+                if result.unsigned_abs() > MAX_SAFE_INTEGER as u128 {
+                    return Err(RuntimeError::new(
+                        operator.clone(),
+                        "Shift result exceeds the 53-bit safe range.".to_string(),
+                    ));
+                }
+                Ok(Object::new(result as f64))
+            }
+            TokenType::GreaterGreater => {
+                let (left, right) = self.integer_operands(operator, left, right)?;
+                let amount = self.shift_amount(operator, right)?;
+                // An arithmetic shift past every bit leaves only the sign.
+                Ok(Object::new((left >> amount.min(63)) as f64))
+            }
             _ => {
-                panic!(
-                    "Unknown binary expression operator {:?}",
-                    expr.operator.token_type
-                );
+                panic!("Unknown binary expression operator {:?}", token_type);
             }
         }
     }
 
-    fn visit_grouping_expr(&mut self, expr: &GroupingExpr) -> Object {
+    fn integer_operands(
+        &self,
+        operator: &Token<'static>,
+        left: &Object,
+        right: &Object,
+    ) -> Result<(i64, i64), RuntimeError> {
+        let (left, right) = self.number_operands(operator, left, right)?;
+        let message = "Operands must be integers within the 53-bit safe range.";
+        Ok((
+            self.integer(operator, left, message)?,
+            self.integer(operator, right, message)?,
+        ))
+    }
+
+    fn integer(
+        &self,
+        token: &Token<'static>,
+        value: f64,
+        message: &str,
+    ) -> Result<i64, RuntimeError> {
+        if value.fract() != 0.0 || value.abs() > MAX_SAFE_INTEGER {
+            return Err(RuntimeError::new(token.clone(), message.to_string()));
+        }
+
+        Ok(value as i64)
+    }
+
+    fn shift_amount(&self, operator: &Token<'static>, amount: i64) -> Result<u32, RuntimeError> {
+        if amount < 0 {
+            return Err(RuntimeError::new(
+                operator.clone(),
+                "Shift amount must not be negative.".to_string(),
+            ));
+        }
+
+        Ok(amount.min(64) as u32)
+    }
+
+    /// `%` and `~/` have no sensible IEEE result for a zero divisor, so
+    /// unlike `/` they report an error instead of yielding NaN or infinity.
+    fn check_divisor(&self, operator: &Token<'static>, divisor: f64) -> Result<(), RuntimeError> {
+        if divisor == 0.0 {
+            Err(RuntimeError::new(
+                operator.clone(),
+                "Division by zero.".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn get_index(
+        &self,
+        bracket: &Token<'static>,
+        object: &Object,
+        index: &Object,
+    ) -> Result<Object, RuntimeError> {
+        if let Some(list) = object.get_value::<Rc<List>>() {
+            list.get(bracket, index)
+        } else if let Some(map) = object.get_value::<Rc<Map>>() {
+            let key = HashKey::from_object(bracket, index)?;
+            map.get(bracket, &key)
+        } else {
+            Err(self.not_indexable(bracket))
+        }
+    }
+
+    fn set_index(
+        &self,
+        bracket: &Token<'static>,
+        object: &Object,
+        index: &Object,
+        value: Object,
+    ) -> Result<(), RuntimeError> {
+        if let Some(list) = object.get_value::<Rc<List>>() {
+            list.set(bracket, index, value)
+        } else if let Some(map) = object.get_value::<Rc<Map>>() {
+            let key = HashKey::from_object(bracket, index)?;
+            map.set(key, value);
+            Ok(())
+        } else {
+            Err(self.not_indexable(bracket))
+        }
+    }
+
+    fn not_indexable(&self, token: &Token<'static>) -> RuntimeError {
+        RuntimeError::new(
+            token.clone(),
+            "Only lists and maps can be indexed.".to_string(),
+        )
+    }
+}
+
+impl Visitor<Result<Object, RuntimeError>> for Interpreter {
+    fn visit_literal_expr(&mut self, expr: &LiteralExpr) -> Result<Object, RuntimeError> {
+        Ok(match expr {
+            LiteralExpr::Str(value) => Object::new(Str::Interned(*value)),
+            LiteralExpr::Num(value) => Object::new(*value),
+            LiteralExpr::Bool(value) => Object::new(*value),
+            LiteralExpr::Nil => Object::new(None::<()>),
+        })
+    }
+
+    fn visit_binary_expr(&mut self, expr: &BinaryExpr) -> Result<Object, RuntimeError> {
+        let left = self.evaluate(*expr.left.clone())?;
+        let right = self.evaluate(*expr.right.clone())?;
+
+        self.binary_operation(&expr.operator, expr.operator.token_type, &left, &right)
+    }
+
+    fn visit_grouping_expr(&mut self, expr: &GroupingExpr) -> Result<Object, RuntimeError> {
         self.evaluate(*expr.expr.clone())
     }
 
-    fn visit_unary_expr(&mut self, expr: &UnaryExpr) -> Object {
-        let value = self.evaluate(Expr::from(*expr.right.clone()));
+    fn visit_unary_expr(&mut self, expr: &UnaryExpr) -> Result<Object, RuntimeError> {
+        let value = self.evaluate(*expr.right.clone())?;
 
         match expr.operator.token_type {
             TokenType::Minus => {
                 if let Some(num) = value.get_value::<f64>() {
-                    Object::new(-num)
+                    Ok(Object::new(-num))
                 } else {
-                    panic!("Unary operand {:?} must be a number", value);
+                    Err(RuntimeError::new(
+                        expr.operator.clone(),
+                        "Operand must be a number.".to_string(),
+                    ))
                 }
             }
-            TokenType::Bang => Object::new(!self.is_truthy(&value)),
+            TokenType::Bang => Ok(Object::new(!self.is_truthy(&value))),
+            TokenType::Tilde => {
+                let Some(&num) = value.get_value::<f64>() else {
+                    return Err(RuntimeError::new(
+                        expr.operator.clone(),
+                        "Operand must be a number.".to_string(),
+                    ));
+                };
+                let num = self.integer(
+                    &expr.operator,
+                    num,
+                    "Operand must be an integer within the 53-bit safe range.",
+                )?;
+                Ok(Object::new(!num as f64))
+            }
             _ => {
                 panic!("Unknown unary token type {:?}", expr.operator.token_type);
             }
         }
     }
 
-    fn visit_expression_stmt(&mut self, stmt: &ExpressionStmt) -> Object {
-        self.evaluate(stmt.expression.clone());
+    fn visit_expression_stmt(&mut self, stmt: &ExpressionStmt) -> Result<Object, RuntimeError> {
+        self.evaluate(stmt.expression.clone())?;
 
-        Object::new(())
+        Ok(Object::new(()))
     }
 
-    fn visit_print_stmt(&mut self, stmt: &PrintStmt) -> Object {
-        let value = self.evaluate(stmt.expression.clone());
-        println!("{}", value.to_string());
+    fn visit_print_stmt(&mut self, stmt: &PrintStmt) -> Result<Object, RuntimeError> {
+        let value = self.evaluate(stmt.expression.clone())?;
+        println!("{}", value);
 
-        Object::new(())
+        Ok(Object::new(()))
     }
 
-    fn visit_var_stmt(&mut self, stmt: &VarStmt) -> Object {
+    fn visit_var_stmt(&mut self, _stmt: &VarStmt) -> Result<Object, RuntimeError> {
         todo!();
     }
 
-    fn visit_variable_expr(&mut self, expr: &VariableExpr) -> Object {
+    fn visit_match_stmt(&mut self, stmt: &MatchStmt) -> Result<Object, RuntimeError> {
+        let subject = self.evaluate(stmt.subject.clone())?;
+
+        for arm in &stmt.arms {
+            let mut matched = false;
+            for pattern in &arm.patterns {
+                matched = match pattern {
+                    Pattern::Wildcard => true,
+                    Pattern::Literal(literal) => {
+                        let value = self.visit_literal_expr(literal)?;
+                        self.is_equal(&value, &subject)
+                    }
+                };
+                if matched {
+                    break;
+                }
+            }
+
+            if !matched {
+                continue;
+            }
+
+            if let Some(guard) = &arm.guard {
+                let guard = self.evaluate(guard.clone())?;
+                if !self.is_truthy(&guard) {
+                    continue;
+                }
+            }
+
+            return self.execute(*arm.body.clone());
+        }
+
+        Ok(Object::new(()))
+    }
+
+    fn visit_variable_expr(&mut self, _expr: &VariableExpr) -> Result<Object, RuntimeError> {
         todo!()
     }
+
+    fn visit_interpolation_expr(
+        &mut self,
+        expr: &InterpolationExpr,
+    ) -> Result<Object, RuntimeError> {
+        let mut result = String::new();
+
+        for part in &expr.parts {
+            let value = self.evaluate(part.clone())?;
+            result.push_str(&value.to_string());
+        }
+
+        Ok(Object::new(Str::from(result)))
+    }
+
+    fn visit_list_expr(&mut self, expr: &ListExpr) -> Result<Object, RuntimeError> {
+        let mut elements = Vec::new();
+
+        for element in &expr.elements {
+            elements.push(self.evaluate(element.clone())?);
+        }
+
+        Ok(Object::new(Rc::new(List::new(elements))))
+    }
+
+    fn visit_index_expr(&mut self, expr: &IndexExpr) -> Result<Object, RuntimeError> {
+        let object = self.evaluate(*expr.object.clone())?;
+        let index = self.evaluate(*expr.index.clone())?;
+
+        self.get_index(&expr.bracket, &object, &index)
+    }
+
+    fn visit_index_set_expr(&mut self, expr: &IndexSetExpr) -> Result<Object, RuntimeError> {
+        let object = self.evaluate(*expr.object.clone())?;
+        let index = self.evaluate(*expr.index.clone())?;
+        let value = self.evaluate(*expr.value.clone())?;
+
+        self.set_index(&expr.bracket, &object, &index, value.clone())?;
+
+        Ok(value)
+    }
+
+    fn visit_index_update_expr(&mut self, expr: &IndexUpdateExpr) -> Result<Object, RuntimeError> {
+        let object = self.evaluate(*expr.object.clone())?;
+        let index = self.evaluate(*expr.index.clone())?;
+        let current = self.get_index(&expr.bracket, &object, &index)?;
+        let value = self.evaluate(*expr.value.clone())?;
+
+        let token_type = match expr.operator.token_type {
+            TokenType::PlusEqual | TokenType::PlusPlus => TokenType::Plus,
+            TokenType::MinusEqual | TokenType::MinusMinus => TokenType::Minus,
+            TokenType::StarEqual => TokenType::Star,
+            TokenType::SlashEqual => TokenType::Slash,
+            _ => panic!("Unknown update operator {:?}", expr.operator.token_type),
+        };
+        let updated = self.binary_operation(&expr.operator, token_type, &current, &value)?;
+        self.set_index(&expr.bracket, &object, &index, updated.clone())?;
+
+        Ok(if expr.postfix { current } else { updated })
+    }
+
+    fn visit_get_expr(&mut self, expr: &GetExpr) -> Result<Object, RuntimeError> {
+        let object = self.evaluate(*expr.object.clone())?;
+        let name = expr.name.clone();
+
+        let (method, names): (Rc<dyn Callable>, &[&str]) =
+            if let Some(list) = object.get_value::<Rc<List>>() {
+                (
+                    Rc::new(ListMethod::new(Rc::clone(list), name)),
+                    &ListMethod::NAMES,
+                )
+            } else if let Some(map) = object.get_value::<Rc<Map>>() {
+                (
+                    Rc::new(MapMethod::new(Rc::clone(map), name)),
+                    &MapMethod::NAMES,
+                )
+            } else {
+                return Err(RuntimeError::new(
+                    name,
+                    "Only lists and maps have properties.".to_string(),
+                ));
+            };
+
+        if !names.contains(&expr.name.lexeme) {
+            return Err(RuntimeError::new(
+                expr.name.clone(),
+                format!("Undefined property '{}'.", expr.name.lexeme),
+            ));
+        }
+
+        Ok(Object::new(method))
+    }
+
+    fn visit_call_expr(&mut self, expr: &CallExpr) -> Result<Object, RuntimeError> {
+        let callee = self.evaluate(*expr.callee.clone())?;
+
+        let mut arguments = Vec::new();
+        for argument in &expr.arguments {
+            arguments.push(self.evaluate(argument.clone())?);
+        }
+
+        let Some(function) = callee.get_value::<Rc<dyn Callable>>() else {
+            return Err(RuntimeError::new(
+                expr.paren.clone(),
+                "Can only call functions and classes.".to_string(),
+            ));
+        };
+
+        if arguments.len() != function.arity() {
+            return Err(RuntimeError::new(
+                expr.paren.clone(),
+                format!(
+                    "Expected {} arguments but got {}.",
+                    function.arity(),
+                    arguments.len()
+                ),
+            ));
+        }
+
+        Rc::clone(function).call(self, arguments)
+    }
+
+    fn visit_map_expr(&mut self, expr: &MapExpr) -> Result<Object, RuntimeError> {
+        let map = Map::new();
+
+        for (key, value) in &expr.entries {
+            let key = self.evaluate(key.clone())?;
+            let key = HashKey::from_object(&expr.brace, &key)?;
+            let value = self.evaluate(value.clone())?;
+            map.set(key, value);
+        }
+
+        Ok(Object::new(Rc::new(map)))
+    }
+
+    fn visit_conditional_expr(&mut self, expr: &ConditionalExpr) -> Result<Object, RuntimeError> {
+        let condition = self.evaluate(*expr.condition.clone())?;
+
+        if self.is_truthy(&condition) {
+            self.evaluate(*expr.then_branch.clone())
+        } else {
+            self.evaluate(*expr.else_branch.clone())
+        }
+    }
+
+    fn visit_comma_expr(&mut self, expr: &CommaExpr) -> Result<Object, RuntimeError> {
+        self.evaluate(*expr.left.clone())?;
+        self.evaluate(*expr.right.clone())
+    }
 }
@@ -1,5 +1,6 @@
 use crate::ast::parser::Parser;
 use crate::ast::scanner::{ScanError, Scanner};
+use crate::ast::stmt::Stmt;
 use crate::interpreter::Interpreter;
 use std::{fs, io};
 
@@ -48,13 +49,32 @@ pub fn run_source(source: String) {
         panic!("Source is empty");
     }
 
+    if let Some(statements) = parse_source(&source) {
+        let mut interpreter = Interpreter;
+        interpreter.interpret(statements);
+    }
+}
+
+/// Scans and parses `source`, printing any errors and warnings. Returns
+/// `None` when the source has errors and must not be run.
+pub fn parse_source(source: &str) -> Option<Vec<Stmt>> {
+    // The parser pulls tokens from the scanner as it goes, so scan errors
+    // are printed as they are found, ahead of the parse errors.
     let mut scanner = Scanner::new(source, ScanError::new());
-    let tokens = scanner.scan_tokens();
-    let mut parser = Parser::new(tokens);
+    let mut parser = Parser::new(scanner.by_ref());
     let statements = parser.parse();
+    for error in parser.errors.borrow().iter() {
+        eprintln!("{}", error);
+    }
+    for warning in &parser.warnings {
+        eprintln!("{}", warning);
+    }
+
+    if parser.error.get() || scanner.error.detected() {
+        return None;
+    }
 
-    let mut interpreter = Interpreter;
-    interpreter.interpret(statements);
+    Some(statements)
 }
 
 pub fn run_main(
@@ -63,6 +83,5 @@ pub fn run_main(
     stdout: impl io::Write,
     exit: impl Fn(i32),
 ) -> io::Result<()> {
-    let exit_code = run(args, stdin, stdout, exit);
-    exit_code
+    run(args, stdin, stdout, exit)
 }
@@ -5,9 +5,6 @@ use std::{env, process};
 
 use lox_rs::run_main;
 
-mod ast;
-mod interpreter;
-
 fn main() -> io::Result<()> {
     run_main(
         env::args().collect(),
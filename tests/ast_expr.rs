@@ -1,6 +1,9 @@
-use lox_rs::ast::expr::{BinaryExpr, Expr, GroupingExpr, LiteralExpr, UnaryExpr};
+use lox_rs::ast::expr::{
+    BinaryExpr, CallExpr, CommaExpr, ConditionalExpr, Expr, GetExpr, GroupingExpr, IndexExpr,
+    IndexSetExpr, IndexUpdateExpr, InterpolationExpr, ListExpr, LiteralExpr, MapExpr, UnaryExpr,
+};
 use lox_rs::ast::expr::{VariableExpr, Visitor};
-use lox_rs::ast::stmt::{ExpressionStmt, PrintStmt, VarStmt};
+use lox_rs::ast::stmt::{ExpressionStmt, MatchStmt, PrintStmt, VarStmt};
 use lox_rs::ast::token::{Token, TokenType};
 
 #[test]
@@ -19,7 +22,7 @@ fn test_create_literal_expr_with_num() {
 
 #[test]
 fn test_create_literal_expr_with_str() {
-    let literal = Expr::Literal(Box::new(LiteralExpr::Str(String::from("Hello"))));
+    let literal = Expr::Literal(Box::new(LiteralExpr::Str("Hello".into())));
     if let Expr::Literal(lit) = literal {
         if let LiteralExpr::Str(value) = *lit {
             assert_eq!(value, "Hello");
@@ -36,7 +39,7 @@ fn test_create_literal_expr_with_bool() {
     let literal = Expr::Literal(Box::new(LiteralExpr::Bool(true)));
     if let Expr::Literal(lit) = literal {
         if let LiteralExpr::Bool(value) = *lit {
-            assert_eq!(value, true);
+            assert!(value);
         } else {
             panic!("Expected LiteralExpr::Bool");
         }
@@ -64,21 +67,65 @@ impl Visitor<String> for MockVisitor {
         "Visited UnaryExpr".to_string()
     }
 
-    fn visit_expression_stmt(&mut self, stmt: &ExpressionStmt) -> String {
+    fn visit_expression_stmt(&mut self, _stmt: &ExpressionStmt) -> String {
         "Visited ExpressionStmt".to_string()
     }
 
-    fn visit_print_stmt(&mut self, stmt: &PrintStmt) -> String {
+    fn visit_print_stmt(&mut self, _stmt: &PrintStmt) -> String {
         "Visited PrintStmt".to_string()
     }
 
-    fn visit_var_stmt(&mut self, stmt: &VarStmt) -> String {
+    fn visit_var_stmt(&mut self, _stmt: &VarStmt) -> String {
         "Visited VarStmt".to_string()
     }
 
-    fn visit_variable_expr(&mut self, expr: &VariableExpr) -> String {
+    fn visit_variable_expr(&mut self, _expr: &VariableExpr) -> String {
         "Visited VariableExpr".to_string()
     }
+
+    fn visit_interpolation_expr(&mut self, _expr: &InterpolationExpr) -> String {
+        "Visited InterpolationExpr".to_string()
+    }
+
+    fn visit_list_expr(&mut self, _expr: &ListExpr) -> String {
+        "Visited ListExpr".to_string()
+    }
+
+    fn visit_index_expr(&mut self, _expr: &IndexExpr) -> String {
+        "Visited IndexExpr".to_string()
+    }
+
+    fn visit_index_set_expr(&mut self, _expr: &IndexSetExpr) -> String {
+        "Visited IndexSetExpr".to_string()
+    }
+
+    fn visit_get_expr(&mut self, _expr: &GetExpr) -> String {
+        "Visited GetExpr".to_string()
+    }
+
+    fn visit_call_expr(&mut self, _expr: &CallExpr) -> String {
+        "Visited CallExpr".to_string()
+    }
+
+    fn visit_map_expr(&mut self, _expr: &MapExpr) -> String {
+        "Visited MapExpr".to_string()
+    }
+
+    fn visit_conditional_expr(&mut self, _expr: &ConditionalExpr) -> String {
+        "Visited ConditionalExpr".to_string()
+    }
+
+    fn visit_comma_expr(&mut self, _expr: &CommaExpr) -> String {
+        "Visited CommaExpr".to_string()
+    }
+
+    fn visit_index_update_expr(&mut self, _expr: &IndexUpdateExpr) -> String {
+        "Visited IndexUpdateExpr".to_string()
+    }
+
+    fn visit_match_stmt(&mut self, _stmt: &MatchStmt) -> String {
+        "Visited MatchStmt".to_string()
+    }
 }
 
 #[test]
@@ -87,7 +134,7 @@ fn test_accept_binary_expr() {
         left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(1.0)))),
         operator: Token {
             token_type: TokenType::Plus,
-            lexeme: "+".to_string(),
+            lexeme: "+",
             literal: None,
             line: 1,
         },
@@ -121,7 +168,7 @@ fn test_accept_unary_expr() {
     let expr = Expr::Unary(Box::new(UnaryExpr {
         operator: Token {
             token_type: TokenType::Minus,
-            lexeme: "-".to_string(),
+            lexeme: "-",
             literal: None,
             line: 1,
         },
@@ -146,7 +193,7 @@ fn test_create_binary_expr() {
     let right_expr = Expr::Literal(Box::new(LiteralExpr::Num(4.0)));
     let operator = Token {
         token_type: TokenType::Plus,
-        lexeme: "+".to_string(),
+        lexeme: "+",
         literal: None,
         line: 1,
     };
@@ -198,7 +245,7 @@ fn test_create_grouping_expr_with_unary() {
     let unary_expr = Expr::Unary(Box::new(UnaryExpr {
         operator: Token {
             token_type: TokenType::Minus,
-            lexeme: "-".to_string(),
+            lexeme: "-",
             literal: None,
             line: 1,
         },
@@ -227,7 +274,7 @@ fn test_create_grouping_expr_with_binary() {
         left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(1.0)))),
         operator: Token {
             token_type: TokenType::Plus,
-            lexeme: "+".to_string(),
+            lexeme: "+",
             literal: None,
             line: 1,
         },
@@ -307,7 +354,7 @@ fn test_extract_num_success() {
 #[test]
 #[should_panic(expected = "Failed to extract number from expression")]
 fn test_extract_num_panic() {
-    let expr = Expr::Literal(Box::new(LiteralExpr::Str("Not a number".to_string())));
+    let expr = Expr::Literal(Box::new(LiteralExpr::Str("Not a number".into())));
     LiteralExpr::extract_num(&expr);
 }
 
@@ -315,7 +362,7 @@ fn test_extract_num_panic() {
 fn test_create_unary_expr_with_minus_operator() {
     let operator = Token {
         token_type: TokenType::Minus,
-        lexeme: "-".to_string(),
+        lexeme: "-",
         literal: None,
         line: 1,
     };
@@ -340,7 +387,7 @@ fn test_create_unary_expr_with_minus_operator() {
 fn test_create_unary_expr_with_not_operator() {
     let operator = Token {
         token_type: TokenType::Bang,
-        lexeme: "!".to_string(),
+        lexeme: "!",
         literal: None,
         line: 1,
     };
@@ -365,11 +412,11 @@ fn test_create_unary_expr_with_not_operator() {
 fn test_create_unary_expr_with_string_literal() {
     let operator = Token {
         token_type: TokenType::Minus, // Assume a hypothetical use of '-' with strings
-        lexeme: "-".to_string(),
+        lexeme: "-",
         literal: None,
         line: 1,
     };
-    let right_expr = Expr::Literal(Box::new(LiteralExpr::Str("Hello".to_string())));
+    let right_expr = Expr::Literal(Box::new(LiteralExpr::Str("Hello".into())));
     let unary_expr = UnaryExpr::new(operator.clone(), right_expr.clone());
 
     assert_eq!(unary_expr.operator.token_type, TokenType::Minus);
@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use lox_rs::ast::interner::Symbol;
+
+#[test]
+fn test_intern_same_string_returns_same_symbol() {
+    let a = Symbol::intern("hello");
+    let b = Symbol::intern("hello");
+
+    assert_eq!(a, b);
+    assert!(std::ptr::eq(a.as_str(), b.as_str()));
+}
+
+#[test]
+fn test_intern_different_strings_returns_different_symbols() {
+    let a = Symbol::intern("hello");
+    let b = Symbol::intern("world");
+
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_intern_owned_and_borrowed_strings_match() {
+    let owned: Symbol = "name".to_string().into();
+    let borrowed: Symbol = "name".into();
+
+    assert_eq!(owned, borrowed);
+}
+
+#[test]
+fn test_symbol_compares_with_str() {
+    let symbol = Symbol::intern("lox");
+
+    assert_eq!(symbol, "lox");
+    assert_eq!(symbol, "lox".to_string());
+    assert_ne!(symbol, "rox");
+}
+
+#[test]
+fn test_symbol_empty_string() {
+    let symbol = Symbol::intern("");
+
+    assert!(symbol.is_empty());
+    assert_eq!(symbol, Symbol::intern(""));
+}
+
+#[test]
+fn test_symbol_hashes_by_identity() {
+    let mut set = HashSet::new();
+    set.insert(Symbol::intern("a"));
+    set.insert(Symbol::intern("a"));
+    set.insert(Symbol::intern("b"));
+
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&Symbol::intern("a")));
+}
+
+#[test]
+fn test_symbol_display_and_debug() {
+    let symbol = Symbol::intern("hi");
+
+    assert_eq!(format!("{}", symbol), "hi");
+    assert_eq!(format!("{:?}", symbol), "\"hi\"");
+}
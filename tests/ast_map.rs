@@ -0,0 +1,92 @@
+use lox_rs::ast::map::{HashKey, Map};
+use lox_rs::ast::object::Object;
+use lox_rs::ast::string::Str;
+use lox_rs::ast::token::{Token, TokenType};
+
+fn token() -> Token<'static> {
+    Token::new(TokenType::LeftBracket, "[", None, 1)
+}
+
+fn string(value: &str) -> Str {
+    value.into()
+}
+
+fn key(object: Object) -> HashKey {
+    HashKey::from_object(&token(), &object).unwrap()
+}
+
+#[test]
+fn test_hash_key_from_hashable_objects() {
+    assert_eq!(key(Object::new(None::<()>)), HashKey::Nil);
+    assert_eq!(key(Object::new(true)), HashKey::Bool(true));
+    assert_eq!(key(Object::new(1.5)), HashKey::Num(1.5_f64.to_bits()));
+    assert_eq!(key(Object::new(string("a"))), HashKey::Str("a".into()));
+}
+
+#[test]
+fn test_hash_key_zero_and_negative_zero_are_the_same_key() {
+    assert_eq!(key(Object::new(0.0)), key(Object::new(-0.0)));
+}
+
+#[test]
+fn test_hash_key_round_trips_to_object() {
+    assert_eq!(key(Object::new(2.0)).to_object().to_string(), "2");
+    assert_eq!(key(Object::new(None::<()>)).to_object().to_string(), "nil");
+    assert_eq!(key(Object::new(string("k"))).to_string(), "k");
+}
+
+#[test]
+fn test_hash_key_rejects_unhashable_objects() {
+    let error = HashKey::from_object(&token(), &Object::new(())).unwrap_err();
+    assert_eq!(
+        error.message,
+        "Map keys must be strings, numbers, booleans or nil."
+    );
+
+    let error = HashKey::from_object(&token(), &Object::new(f64::NAN)).unwrap_err();
+    assert_eq!(error.message, "NaN can't be used as a map key.");
+}
+
+#[test]
+fn test_map_set_get_and_overwrite_keep_insertion_order() {
+    let map = Map::new();
+    map.set(key(Object::new(string("b"))), Object::new(1.0));
+    map.set(key(Object::new(string("a"))), Object::new(2.0));
+    map.set(key(Object::new(string("b"))), Object::new(3.0));
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.to_string(), "{\"b\": 3, \"a\": 2}");
+    let value = map.get(&token(), &key(Object::new(string("a"))));
+    assert_eq!(value.unwrap().to_string(), "2");
+}
+
+#[test]
+fn test_map_remove_reindexes_later_entries() {
+    let map = Map::new();
+    for (i, name) in ["a", "b", "c"].iter().enumerate() {
+        map.set(key(Object::new(string(name))), Object::new(i as f64));
+    }
+
+    let removed = map.remove(&token(), &key(Object::new(string("a"))));
+    assert_eq!(removed.unwrap().to_string(), "0");
+
+    let c = key(Object::new(string("c")));
+    assert_eq!(map.get(&token(), &c).unwrap().to_string(), "2");
+    assert_eq!(map.to_string(), "{\"b\": 1, \"c\": 2}");
+    assert!(!map.contains(&key(Object::new(string("a")))));
+}
+
+#[test]
+fn test_map_missing_key_errors() {
+    let map = Map::new();
+    let missing = key(Object::new(string("x")));
+
+    assert_eq!(
+        map.get(&token(), &missing).unwrap_err().message,
+        "Undefined key 'x'."
+    );
+    assert_eq!(
+        map.remove(&token(), &missing).unwrap_err().message,
+        "Undefined key 'x'."
+    );
+}
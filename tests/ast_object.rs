@@ -1,4 +1,5 @@
 use lox_rs::ast::object::Object;
+use lox_rs::ast::string::Str;
 
 #[test]
 fn test_create_object_with_f64() {
@@ -152,7 +153,7 @@ fn test_to_string_with_f64() {
 
 #[test]
 fn test_to_string_with_string() {
-    let obj = Object::new(String::from("Hello, world!"));
+    let obj = Object::new(Str::from("Hello, world!"));
     assert_eq!(obj.to_string(), "Hello, world!");
 }
 
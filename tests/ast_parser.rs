@@ -1,5 +1,10 @@
-use lox_rs::ast::expr::{BinaryExpr, Expr, GroupingExpr, LiteralExpr, UnaryExpr};
+use lox_rs::ast::expr::{
+    BinaryExpr, Expr, GroupingExpr, InterpolationExpr, LiteralExpr, UnaryExpr,
+};
 use lox_rs::ast::parser::{ParseError, Parser};
+use lox_rs::ast::printer::Printer;
+use lox_rs::ast::scanner::{ScanError, Scanner};
+use lox_rs::ast::stmt::{MatchArm, MatchStmt, Pattern, PrintStmt, Stmt};
 use lox_rs::ast::token::{Token, TokenType};
 use std::error::Error;
 
@@ -44,69 +49,65 @@ fn test_parse_error_debug() {
 #[test]
 fn test_parser_initialization() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "1".to_string(),
-            Some(LiteralExpr::Num(2f64)),
-            1,
-        ),
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "2".to_string(),
-            Some(LiteralExpr::Num(3f64)),
-            1,
-        ),
+        Token::new(TokenType::Number, "1", Some(LiteralExpr::Num(2f64)), 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Number, "2", Some(LiteralExpr::Num(3f64)), 1),
     ];
     let parser = Parser::new(tokens.clone());
 
-    assert_eq!(parser.tokens, tokens);
+    assert_eq!(parser.tokens.as_slice(), tokens.as_slice());
+    assert!(parser.lookahead.is_empty());
+    assert!(parser.last.is_none());
     assert_eq!(parser.current, 0);
-    assert_eq!(parser.error.get(), false);
+    assert!(!parser.error.get());
+}
+
+#[test]
+fn test_parser_pulls_tokens_lazily_from_scanner() {
+    let scanner = Scanner::new("1 + 2;", ScanError::new());
+    let mut parser = Parser::new(scanner);
+
+    let expr = parser.primary().unwrap();
+
+    assert_eq!(expr, Expr::Literal(Box::new(LiteralExpr::Num(1.0))));
+    assert_eq!(parser.current, 1);
+    assert!(!parser.tokens.finished);
+    assert!(parser.tokens.current < parser.tokens.source.len());
+}
+
+#[test]
+fn test_parser_parses_statements_from_scanner() {
+    let scanner = Scanner::new("print 1 + 2;", ScanError::new());
+    let mut parser = Parser::new(scanner);
+
+    let statements = parser.parse();
+
+    assert_eq!(statements.len(), 1);
+    assert!(parser.tokens.finished);
 }
 
 #[test]
 fn test_parser_error_handling() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "1".to_string(),
-            Some(LiteralExpr::Num(2f64)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "1", Some(LiteralExpr::Num(2f64)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let parser = Parser::new(tokens);
-    let token = Token::new(
-        TokenType::Eof,
-        "\0".to_string(),
-        Some(LiteralExpr::Str("\0".to_string())),
-        1,
-    );
+    let token = Token::new(TokenType::Eof, "\0", Some(LiteralExpr::Str("\0".into())), 1);
 
-    assert_eq!(parser.error.get(), false);
-    parser.error(&token, &"Test error".to_string());
-    assert_eq!(parser.error.get(), true);
+    assert!(!parser.error.get());
+    parser.error(&token, "Test error");
+    assert!(parser.error.get());
     parser.error.set(false);
-    assert_eq!(parser.error.get(), false);
+    assert!(!parser.error.get());
 }
 
 #[test]
 fn test_parser_advance() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "1".to_string(),
-            Some(LiteralExpr::Num(2f64)),
-            1,
-        ),
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "2".to_string(),
-            Some(LiteralExpr::Num(3f64)),
-            1,
-        ),
+        Token::new(TokenType::Number, "1", Some(LiteralExpr::Num(2f64)), 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Number, "2", Some(LiteralExpr::Num(3f64)), 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -170,7 +171,7 @@ fn test_parser_advance() {
 // }
 
 #[test]
-#[should_panic(expected = "index out of bounds")]
+#[should_panic(expected = "Token stream ended without an Eof token")]
 fn test_parse_error_final_eof_token_missing() {
     let mut parser = Parser::new(vec![]);
     parser.parse();
@@ -207,11 +208,11 @@ fn test_parse_error_final_eof_token_missing() {
 #[test]
 fn test_synchronize_stops_at_semicolon() {
     let tokens = vec![
-        Token::new(TokenType::Identifier, "var".to_string(), None, 1),
-        Token::new(TokenType::Number, "42".to_string(), None, 1),
-        Token::new(TokenType::Semicolon, ";".to_string(), None, 1),
-        Token::new(TokenType::Identifier, "x".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Identifier, "var", None, 1),
+        Token::new(TokenType::Number, "42", None, 1),
+        Token::new(TokenType::Semicolon, ";", None, 1),
+        Token::new(TokenType::Identifier, "x", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -226,11 +227,11 @@ fn test_synchronize_stops_at_semicolon() {
 #[test]
 fn test_synchronize_stops_at_class_keyword() {
     let tokens = vec![
-        Token::new(TokenType::Identifier, "foo".to_string(), None, 1),
-        Token::new(TokenType::Number, "42".to_string(), None, 1),
-        Token::new(TokenType::Class, "class".to_string(), None, 1),
-        Token::new(TokenType::Identifier, "x".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Identifier, "foo", None, 1),
+        Token::new(TokenType::Number, "42", None, 1),
+        Token::new(TokenType::Class, "class", None, 1),
+        Token::new(TokenType::Identifier, "x", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -245,10 +246,10 @@ fn test_synchronize_stops_at_class_keyword() {
 #[test]
 fn test_synchronize_reaches_end_without_stopping() {
     let tokens = vec![
-        Token::new(TokenType::Identifier, "foo".to_string(), None, 1),
-        Token::new(TokenType::Number, "42".to_string(), None, 1),
-        Token::new(TokenType::Identifier, "x".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Identifier, "foo", None, 1),
+        Token::new(TokenType::Number, "42", None, 1),
+        Token::new(TokenType::Identifier, "x", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -263,20 +264,10 @@ fn test_synchronize_reaches_end_without_stopping() {
 #[test]
 fn test_expression_calls_equality() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::EqualEqual, "==".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "43".to_string(),
-            Some(LiteralExpr::Num(43.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::EqualEqual, "==", None, 1),
+        Token::new(TokenType::Number, "43", Some(LiteralExpr::Num(43.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -286,7 +277,7 @@ fn test_expression_calls_equality() {
         result,
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-            operator: Token::new(TokenType::EqualEqual, "==".to_string(), None, 1),
+            operator: Token::new(TokenType::EqualEqual, "==", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(43.0)))),
         }))
     );
@@ -295,20 +286,10 @@ fn test_expression_calls_equality() {
 #[test]
 fn test_equality_single_equality() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::EqualEqual, "==".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "43".to_string(),
-            Some(LiteralExpr::Num(43.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::EqualEqual, "==", None, 1),
+        Token::new(TokenType::Number, "43", Some(LiteralExpr::Num(43.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -318,7 +299,7 @@ fn test_equality_single_equality() {
         result,
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-            operator: Token::new(TokenType::EqualEqual, "==".to_string(), None, 1),
+            operator: Token::new(TokenType::EqualEqual, "==", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(43.0)))),
         }))
     );
@@ -327,27 +308,12 @@ fn test_equality_single_equality() {
 #[test]
 fn test_equality_multiple_comparisons() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::EqualEqual, "==".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "43".to_string(),
-            Some(LiteralExpr::Num(43.0)),
-            1,
-        ),
-        Token::new(TokenType::BangEqual, "!=".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "44".to_string(),
-            Some(LiteralExpr::Num(44.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::EqualEqual, "==", None, 1),
+        Token::new(TokenType::Number, "43", Some(LiteralExpr::Num(43.0)), 1),
+        Token::new(TokenType::BangEqual, "!=", None, 1),
+        Token::new(TokenType::Number, "44", Some(LiteralExpr::Num(44.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -358,10 +324,10 @@ fn test_equality_multiple_comparisons() {
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Binary(Box::new(BinaryExpr {
                 left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-                operator: Token::new(TokenType::EqualEqual, "==".to_string(), None, 1),
+                operator: Token::new(TokenType::EqualEqual, "==", None, 1),
                 right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(43.0)))),
             }))),
-            operator: Token::new(TokenType::BangEqual, "!=".to_string(), None, 1),
+            operator: Token::new(TokenType::BangEqual, "!=", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(44.0)))),
         }))
     );
@@ -370,13 +336,8 @@ fn test_equality_multiple_comparisons() {
 #[test]
 fn test_equality_no_comparison() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -388,72 +349,52 @@ fn test_equality_no_comparison() {
 #[test]
 fn test_is_at_end_true() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
 
     // Initially not at the end
-    assert_eq!(parser.is_at_end(), false);
+    assert!(!parser.is_at_end());
 
     // Move to Eof token
-    parser.current += 1;
-    assert_eq!(parser.is_at_end(), true); // Now should be at the end
+    parser.advance();
+    assert!(parser.is_at_end()); // Now should be at the end
 }
 
 #[test]
 fn test_is_at_end_false() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(
-            TokenType::Number,
-            "43".to_string(),
-            Some(LiteralExpr::Num(43.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Number, "43", Some(LiteralExpr::Num(43.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
-    let parser = Parser::new(tokens);
+    let mut parser = Parser::new(tokens);
 
     // Initially, the parser should not be at the end
-    assert_eq!(parser.is_at_end(), false);
+    assert!(!parser.is_at_end());
 }
 
 #[test]
 fn test_is_at_end_on_empty_input() {
-    let tokens = vec![Token::new(TokenType::Eof, "".to_string(), None, 1)];
+    let tokens = vec![Token::new(TokenType::Eof, "", None, 1)];
 
-    let parser = Parser::new(tokens);
+    let mut parser = Parser::new(tokens);
 
     // Should immediately be at the end since the only token is Eof
-    assert_eq!(parser.is_at_end(), true);
+    assert!(parser.is_at_end());
 }
 
 #[test]
 fn test_check_returns_true_for_matching_token() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
-    let parser = Parser::new(tokens);
+    let mut parser = Parser::new(tokens);
 
     // The first token is a Number, so check should return true
     assert!(parser.check(TokenType::Number));
@@ -462,16 +403,11 @@ fn test_check_returns_true_for_matching_token() {
 #[test]
 fn test_check_returns_false_for_non_matching_token() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
-    let parser = Parser::new(tokens);
+    let mut parser = Parser::new(tokens);
 
     // The first token is not a Plus, so check should return false
     assert!(!parser.check(TokenType::Plus));
@@ -480,19 +416,14 @@ fn test_check_returns_false_for_non_matching_token() {
 #[test]
 fn test_check_returns_false_at_end_of_input() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
 
     // Move to the end of the tokens
-    parser.current = 1;
+    parser.advance();
 
     // Since we're at the end, check should return false regardless of the token type
     assert!(!parser.check(TokenType::Number));
@@ -501,14 +432,9 @@ fn test_check_returns_false_at_end_of_input() {
 #[test]
 fn test_previous_after_one_advance() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -518,26 +444,16 @@ fn test_previous_after_one_advance() {
 
     assert_eq!(
         previous_token,
-        &Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1
-        )
+        &Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1)
     );
 }
 
 #[test]
 fn test_previous_after_multiple_advances() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -546,39 +462,31 @@ fn test_previous_after_multiple_advances() {
 
     let previous_token = parser.previous();
 
-    assert_eq!(
-        previous_token,
-        &Token::new(TokenType::Plus, "+".to_string(), None, 1)
-    );
+    assert_eq!(previous_token, &Token::new(TokenType::Plus, "+", None, 1));
 }
 
 #[test]
-#[should_panic(expected = "attempt to subtract with overflow")]
+#[should_panic(expected = "No token has been consumed yet")]
 fn test_previous_at_start_panics() {
     let tokens = vec![Token::new(
         TokenType::Number,
-        "42".to_string(),
+        "42",
         Some(LiteralExpr::Num(42.0)),
         1,
     )];
 
     let parser = Parser::new(tokens);
 
-    // Calling previous without advancing should panic due to the current index being 0.
+    // Calling previous without advancing should panic since nothing has been consumed.
     let _previous_token = parser.previous();
 }
 
 #[test]
 fn test_advance_moves_to_next_token() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -586,31 +494,21 @@ fn test_advance_moves_to_next_token() {
     let first_advanced_token = parser.advance();
     assert_eq!(
         first_advanced_token,
-        &Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1
-        )
+        &Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1)
     );
 
     let second_advanced_token = parser.advance();
     assert_eq!(
         second_advanced_token,
-        &Token::new(TokenType::Plus, "+".to_string(), None, 1)
+        &Token::new(TokenType::Plus, "+", None, 1)
     );
 }
 
 #[test]
 fn test_advance_does_not_move_past_end() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
 
     let mut parser = Parser::new(tokens);
@@ -618,31 +516,21 @@ fn test_advance_does_not_move_past_end() {
     let advanced_token = parser.advance();
     assert_eq!(
         advanced_token,
-        &Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1
-        )
+        &Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1)
     );
 
     let advanced_token_again = parser.advance();
     assert_eq!(
         advanced_token_again,
-        &Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1
-        )
+        &Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1)
     );
 }
 
 #[test]
 fn test_match_single_token() {
     let tokens = vec![
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -652,8 +540,8 @@ fn test_match_single_token() {
 #[test]
 fn test_match_multiple_tokens() {
     let tokens = vec![
-        Token::new(TokenType::Minus, "-".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Minus, "-", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -663,8 +551,8 @@ fn test_match_multiple_tokens() {
 #[test]
 fn test_no_match() {
     let tokens = vec![
-        Token::new(TokenType::Star, "*".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Star, "*", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -673,7 +561,7 @@ fn test_no_match() {
 
 #[test]
 fn test_match_at_end() {
-    let tokens = vec![Token::new(TokenType::Eof, "".to_string(), None, 1)];
+    let tokens = vec![Token::new(TokenType::Eof, "", None, 1)];
     let mut parser = Parser::new(tokens);
 
     assert!(!parser.match_tokens(&[TokenType::Eof]));
@@ -682,9 +570,9 @@ fn test_match_at_end() {
 #[test]
 fn test_match_token_advances_parser() {
     let tokens = vec![
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(TokenType::Minus, "-".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Minus, "-", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -695,7 +583,7 @@ fn test_match_token_advances_parser() {
 #[test]
 fn test_error_sets_error_state() {
     let parser = Parser::new(vec![]);
-    let token = Token::new(TokenType::Identifier, "foo".to_string(), None, 1);
+    let token = Token::new(TokenType::Identifier, "foo", None, 1);
     parser.error(&token, "Unexpected token");
 
     assert!(parser.error.get(), "Error state should be set to true");
@@ -704,21 +592,38 @@ fn test_error_sets_error_state() {
 #[test]
 fn test_error_message_formatting() {
     let parser = Parser::new(vec![]);
-    let token = Token::new(TokenType::Identifier, "foo".to_string(), None, 1);
+    let token = Token::new(TokenType::Identifier, "foo", None, 1);
     let message = parser.error(&token, "Unexpected token");
 
     assert_eq!(message, "Error at foo: Unexpected token");
 }
 
 #[test]
-fn test_error_with_different_token() {
+fn test_error_is_recorded() {
     let parser = Parser::new(vec![]);
-    let token = Token::new(
-        TokenType::Number,
-        "42".to_string(),
-        Some(LiteralExpr::Num(42.0)),
-        1,
+    let token = Token::new(TokenType::Identifier, "foo", None, 1);
+    parser.error(&token, "Unexpected token");
+
+    assert_eq!(
+        *parser.errors.borrow(),
+        vec!["Error at foo: Unexpected token".to_string()]
+    );
+}
+
+#[test]
+fn test_invalid_assignment_target_is_recorded() {
+    let parser = parse_statements("print 1 = 2;");
+
+    assert_eq!(
+        *parser.errors.borrow(),
+        vec!["Error at =: Invalid assignment target.".to_string()]
     );
+}
+
+#[test]
+fn test_error_with_different_token() {
+    let parser = Parser::new(vec![]);
+    let token = Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1);
     let message = parser.error(&token, "Expected an identifier");
 
     assert_eq!(message, "Error at 42: Expected an identifier");
@@ -728,8 +633,8 @@ fn test_error_with_different_token() {
 #[test]
 fn test_consume_successful() {
     let tokens = vec![
-        Token::new(TokenType::Identifier, "foo".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Identifier, "foo", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -742,13 +647,8 @@ fn test_consume_successful() {
 #[test]
 fn test_consume_error() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -760,7 +660,7 @@ fn test_consume_error() {
 
 #[test]
 fn test_consume_at_end() {
-    let tokens = vec![Token::new(TokenType::Eof, "".to_string(), None, 1)];
+    let tokens = vec![Token::new(TokenType::Eof, "", None, 1)];
     let mut parser = Parser::new(tokens);
 
     let result = parser.consume(TokenType::Identifier, "Expected an identifier");
@@ -772,9 +672,9 @@ fn test_consume_at_end() {
 #[test]
 fn test_consume_advances_on_success() {
     let tokens = vec![
-        Token::new(TokenType::Identifier, "foo".to_string(), None, 1),
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Identifier, "foo", None, 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -786,8 +686,8 @@ fn test_consume_advances_on_success() {
 #[test]
 fn test_primary_false_literal() {
     let tokens = vec![
-        Token::new(TokenType::False, "false".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::False, "false", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -802,8 +702,8 @@ fn test_primary_false_literal() {
 #[test]
 fn test_primary_true_literal() {
     let tokens = vec![
-        Token::new(TokenType::True, "true".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::True, "true", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -818,8 +718,8 @@ fn test_primary_true_literal() {
 #[test]
 fn test_primary_nil_literal() {
     let tokens = vec![
-        Token::new(TokenType::Nil, "nil".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Nil, "nil", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -831,13 +731,8 @@ fn test_primary_nil_literal() {
 #[test]
 fn test_primary_number_literal() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -854,11 +749,11 @@ fn test_primary_string_literal() {
     let tokens = vec![
         Token::new(
             TokenType::String,
-            "42".to_string(),
-            Some(LiteralExpr::Str("42".to_string())),
+            "42",
+            Some(LiteralExpr::Str("42".into())),
             1,
         ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -866,22 +761,17 @@ fn test_primary_string_literal() {
     assert!(result.is_ok());
     assert_eq!(
         result.unwrap(),
-        Expr::Literal(Box::new(LiteralExpr::Str("42".to_string())))
+        Expr::Literal(Box::new(LiteralExpr::Str("42".into())))
     );
 }
 
 #[test]
 fn test_primary_grouping_expression() {
     let tokens = vec![
-        Token::new(TokenType::LeftParen, "(".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::RightParen, ")".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::LeftParen, "(", None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::RightParen, ")", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -898,45 +788,43 @@ fn test_primary_grouping_expression() {
 #[test]
 fn test_primary_error_on_invalid_token() {
     let tokens = vec![
-        Token::new(TokenType::Identifier, "foo".to_string(), None, 1),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Identifier, "foo", None, 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
     let result = parser.primary();
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Expect expression.".to_string());
+    assert_eq!(
+        result.unwrap_err(),
+        "Error at foo: Expect expression.".to_string()
+    );
+    assert!(parser.error.get());
 }
 
 #[test]
 fn test_primary_unexpected_literal_type_error() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "true".to_string(),
-            Some(LiteralExpr::Bool(true)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "true", Some(LiteralExpr::Bool(true)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
     let result = parser.primary();
     assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Unexpected literal type".to_string());
+    assert_eq!(
+        result.unwrap_err(),
+        "Error at true: Unexpected literal type".to_string()
+    );
+    assert!(parser.error.get());
 }
 
 #[test]
 fn test_unary_bang_operator_with_true() {
     let tokens = vec![
-        Token::new(TokenType::Bang, "!".to_string(), None, 1),
-        Token::new(
-            TokenType::True,
-            "true".to_string(),
-            Some(LiteralExpr::Bool(true)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Bang, "!", None, 1),
+        Token::new(TokenType::True, "true", Some(LiteralExpr::Bool(true)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -944,7 +832,7 @@ fn test_unary_bang_operator_with_true() {
     assert_eq!(
         result,
         Expr::Unary(Box::new(UnaryExpr {
-            operator: Token::new(TokenType::Bang, "!".to_string(), None, 1),
+            operator: Token::new(TokenType::Bang, "!", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Bool(true)))),
         }))
     );
@@ -953,14 +841,9 @@ fn test_unary_bang_operator_with_true() {
 #[test]
 fn test_unary_minus_operator() {
     let tokens = vec![
-        Token::new(TokenType::Minus, "-".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Minus, "-", None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -968,7 +851,7 @@ fn test_unary_minus_operator() {
     assert_eq!(
         result,
         Expr::Unary(Box::new(UnaryExpr {
-            operator: Token::new(TokenType::Minus, "-".to_string(), None, 1),
+            operator: Token::new(TokenType::Minus, "-", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
         }))
     );
@@ -977,13 +860,8 @@ fn test_unary_minus_operator() {
 #[test]
 fn test_no_unary_operator() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -994,13 +872,8 @@ fn test_no_unary_operator() {
 #[test]
 fn test_factor_single_term() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1011,20 +884,10 @@ fn test_factor_single_term() {
 #[test]
 fn test_factor_multiplication() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Star, "*".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "8".to_string(),
-            Some(LiteralExpr::Num(8.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Star, "*", None, 1),
+        Token::new(TokenType::Number, "8", Some(LiteralExpr::Num(8.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1033,7 +896,7 @@ fn test_factor_multiplication() {
         result,
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-            operator: Token::new(TokenType::Star, "*".to_string(), None, 1),
+            operator: Token::new(TokenType::Star, "*", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(8.0)))),
         }))
     );
@@ -1042,20 +905,10 @@ fn test_factor_multiplication() {
 #[test]
 fn test_factor_division() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Slash, "/".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "8".to_string(),
-            Some(LiteralExpr::Num(8.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Slash, "/", None, 1),
+        Token::new(TokenType::Number, "8", Some(LiteralExpr::Num(8.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1064,7 +917,7 @@ fn test_factor_division() {
         result,
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-            operator: Token::new(TokenType::Slash, "/".to_string(), None, 1),
+            operator: Token::new(TokenType::Slash, "/", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(8.0)))),
         }))
     );
@@ -1073,27 +926,12 @@ fn test_factor_division() {
 #[test]
 fn test_factor_multiple_operations() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Star, "*".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "8".to_string(),
-            Some(LiteralExpr::Num(8.0)),
-            1,
-        ),
-        Token::new(TokenType::Slash, "/".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "2".to_string(),
-            Some(LiteralExpr::Num(2.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Star, "*", None, 1),
+        Token::new(TokenType::Number, "8", Some(LiteralExpr::Num(8.0)), 1),
+        Token::new(TokenType::Slash, "/", None, 1),
+        Token::new(TokenType::Number, "2", Some(LiteralExpr::Num(2.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1103,10 +941,10 @@ fn test_factor_multiple_operations() {
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Binary(Box::new(BinaryExpr {
                 left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-                operator: Token::new(TokenType::Star, "*".to_string(), None, 1),
+                operator: Token::new(TokenType::Star, "*", None, 1),
                 right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(8.0)))),
             }))),
-            operator: Token::new(TokenType::Slash, "/".to_string(), None, 1),
+            operator: Token::new(TokenType::Slash, "/", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(2.0)))),
         }))
     );
@@ -1115,13 +953,8 @@ fn test_factor_multiple_operations() {
 #[test]
 fn test_term_single_term() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1132,20 +965,10 @@ fn test_term_single_term() {
 #[test]
 fn test_term_addition() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "8".to_string(),
-            Some(LiteralExpr::Num(8.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Number, "8", Some(LiteralExpr::Num(8.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1154,7 +977,7 @@ fn test_term_addition() {
         result,
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-            operator: Token::new(TokenType::Plus, "+".to_string(), None, 1),
+            operator: Token::new(TokenType::Plus, "+", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(8.0)))),
         }))
     );
@@ -1163,20 +986,10 @@ fn test_term_addition() {
 #[test]
 fn test_term_subtraction() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Minus, "-".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "8".to_string(),
-            Some(LiteralExpr::Num(8.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Minus, "-", None, 1),
+        Token::new(TokenType::Number, "8", Some(LiteralExpr::Num(8.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1185,7 +998,7 @@ fn test_term_subtraction() {
         result,
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-            operator: Token::new(TokenType::Minus, "-".to_string(), None, 1),
+            operator: Token::new(TokenType::Minus, "-", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(8.0)))),
         }))
     );
@@ -1194,27 +1007,12 @@ fn test_term_subtraction() {
 #[test]
 fn test_term_multiple_operations() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Plus, "+".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "8".to_string(),
-            Some(LiteralExpr::Num(8.0)),
-            1,
-        ),
-        Token::new(TokenType::Minus, "-".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "2".to_string(),
-            Some(LiteralExpr::Num(2.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Token::new(TokenType::Number, "8", Some(LiteralExpr::Num(8.0)), 1),
+        Token::new(TokenType::Minus, "-", None, 1),
+        Token::new(TokenType::Number, "2", Some(LiteralExpr::Num(2.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1224,10 +1022,10 @@ fn test_term_multiple_operations() {
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Binary(Box::new(BinaryExpr {
                 left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-                operator: Token::new(TokenType::Plus, "+".to_string(), None, 1),
+                operator: Token::new(TokenType::Plus, "+", None, 1),
                 right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(8.0)))),
             }))),
-            operator: Token::new(TokenType::Minus, "-".to_string(), None, 1),
+            operator: Token::new(TokenType::Minus, "-", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(2.0)))),
         }))
     );
@@ -1236,13 +1034,8 @@ fn test_term_multiple_operations() {
 #[test]
 fn test_comparison_single_term() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1253,20 +1046,10 @@ fn test_comparison_single_term() {
 #[test]
 fn test_comparison_greater() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Greater, ">".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "8".to_string(),
-            Some(LiteralExpr::Num(8.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Greater, ">", None, 1),
+        Token::new(TokenType::Number, "8", Some(LiteralExpr::Num(8.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1275,7 +1058,7 @@ fn test_comparison_greater() {
         result,
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-            operator: Token::new(TokenType::Greater, ">".to_string(), None, 1),
+            operator: Token::new(TokenType::Greater, ">", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(8.0)))),
         }))
     );
@@ -1284,20 +1067,10 @@ fn test_comparison_greater() {
 #[test]
 fn test_comparison_less_equal() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::LessEqual, "<=".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "50".to_string(),
-            Some(LiteralExpr::Num(50.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::LessEqual, "<=", None, 1),
+        Token::new(TokenType::Number, "50", Some(LiteralExpr::Num(50.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1306,7 +1079,7 @@ fn test_comparison_less_equal() {
         result,
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-            operator: Token::new(TokenType::LessEqual, "<=".to_string(), None, 1),
+            operator: Token::new(TokenType::LessEqual, "<=", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(50.0)))),
         }))
     );
@@ -1315,27 +1088,12 @@ fn test_comparison_less_equal() {
 #[test]
 fn test_comparison_multiple_operations() {
     let tokens = vec![
-        Token::new(
-            TokenType::Number,
-            "42".to_string(),
-            Some(LiteralExpr::Num(42.0)),
-            1,
-        ),
-        Token::new(TokenType::Greater, ">".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "8".to_string(),
-            Some(LiteralExpr::Num(8.0)),
-            1,
-        ),
-        Token::new(TokenType::LessEqual, "<=".to_string(), None, 1),
-        Token::new(
-            TokenType::Number,
-            "50".to_string(),
-            Some(LiteralExpr::Num(50.0)),
-            1,
-        ),
-        Token::new(TokenType::Eof, "".to_string(), None, 1),
+        Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 1),
+        Token::new(TokenType::Greater, ">", None, 1),
+        Token::new(TokenType::Number, "8", Some(LiteralExpr::Num(8.0)), 1),
+        Token::new(TokenType::LessEqual, "<=", None, 1),
+        Token::new(TokenType::Number, "50", Some(LiteralExpr::Num(50.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
     ];
     let mut parser = Parser::new(tokens);
 
@@ -1345,11 +1103,500 @@ fn test_comparison_multiple_operations() {
         Expr::Binary(Box::new(BinaryExpr {
             left: Box::new(Expr::Binary(Box::new(BinaryExpr {
                 left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(42.0)))),
-                operator: Token::new(TokenType::Greater, ">".to_string(), None, 1),
+                operator: Token::new(TokenType::Greater, ">", None, 1),
                 right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(8.0)))),
             }))),
-            operator: Token::new(TokenType::LessEqual, "<=".to_string(), None, 1),
+            operator: Token::new(TokenType::LessEqual, "<=", None, 1),
             right: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(50.0)))),
         }))
     );
 }
+
+#[test]
+fn test_primary_string_interpolation() {
+    let mut parser = Parser::new(Scanner::new("\"a ${1} b ${2} c\"", ScanError::new()));
+
+    let result = parser.primary().unwrap();
+
+    assert_eq!(
+        result,
+        Expr::Interpolation(Box::new(InterpolationExpr::new(vec![
+            Expr::Literal(Box::new(LiteralExpr::Str("a ".into()))),
+            Expr::Literal(Box::new(LiteralExpr::Num(1.0))),
+            Expr::Literal(Box::new(LiteralExpr::Str(" b ".into()))),
+            Expr::Literal(Box::new(LiteralExpr::Num(2.0))),
+            Expr::Literal(Box::new(LiteralExpr::Str(" c".into()))),
+        ])))
+    );
+    assert!(parser.is_at_end());
+}
+
+#[test]
+fn test_primary_empty_interpolation() {
+    let mut parser = Parser::new(Scanner::new("\"a${}b\"", ScanError::new()));
+
+    assert!(parser.primary().is_ok());
+    assert!(parser.is_at_end());
+    assert_eq!(
+        *parser.errors.borrow(),
+        vec!["Error at }b\": Expect expression inside '${}'.".to_string()]
+    );
+}
+
+#[test]
+fn test_primary_interpolation_missing_end_segment() {
+    let tokens = vec![
+        Token::new(
+            TokenType::Interpolation,
+            "\"a ${",
+            Some(LiteralExpr::Str("a ".into())),
+            1,
+        ),
+        Token::new(TokenType::Number, "1", Some(LiteralExpr::Num(1.0)), 1),
+        Token::new(TokenType::Eof, "", None, 1),
+    ];
+    let mut parser = Parser::new(tokens);
+
+    let result = parser.primary();
+
+    assert!(result.is_err());
+    assert!(parser.error.get());
+}
+
+fn parse_expression(source: &str) -> (String, bool) {
+    let mut parser = Parser::new(Scanner::new(source, ScanError::new()));
+    let expr = parser.expression();
+    assert!(parser.is_at_end());
+    (Printer.print(&expr), parser.error.get())
+}
+
+#[test]
+fn test_list_literal() {
+    assert_eq!(parse_expression("[]"), ("(list)".to_string(), false));
+    assert_eq!(
+        parse_expression("[1, 2 + 3, \"a\"]"),
+        ("(list 1 (+ 2 3) a)".to_string(), false)
+    );
+}
+
+#[test]
+fn test_list_literal_trailing_comma() {
+    assert_eq!(
+        parse_expression("[1, 2,]"),
+        ("(list 1 2)".to_string(), false)
+    );
+}
+
+#[test]
+fn test_list_literal_missing_bracket() {
+    let mut parser = Parser::new(Scanner::new("[1, 2", ScanError::new()));
+
+    assert!(parser.primary().is_err());
+    assert!(parser.error.get());
+}
+
+#[test]
+fn test_index_and_call_chain() {
+    assert_eq!(
+        parse_expression("[[1]][0][0]"),
+        ("(index (index (list (list 1)) 0) 0)".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("[1].slice(0, 1).len()"),
+        (
+            "(call (. (call (. (list 1) slice) 0 1) len))".to_string(),
+            false
+        )
+    );
+}
+
+#[test]
+fn test_index_assignment_is_right_associative() {
+    assert_eq!(
+        parse_expression("[1][0] = [2][0] = 3"),
+        (
+            "(index= (list 1) 0 (index= (list 2) 0 3))".to_string(),
+            false
+        )
+    );
+}
+
+#[test]
+fn test_invalid_assignment_target() {
+    let (printed, error) = parse_expression("1 + 2 = 3");
+
+    assert_eq!(printed, "(+ 1 2)");
+    assert!(error);
+}
+
+#[test]
+fn test_map_literal() {
+    assert_eq!(parse_expression("{}"), ("(map)".to_string(), false));
+    assert_eq!(
+        parse_expression("{\"a\": 1, 2: [3], nil: true,}"),
+        ("(map a 1 2 (list 3) nil true)".to_string(), false)
+    );
+}
+
+#[test]
+fn test_map_literal_inside_interpolation() {
+    assert_eq!(
+        parse_expression("\"${{1: 2}[1]}\""),
+        ("(interpolate  (index (map 1 2) 1) )".to_string(), false)
+    );
+}
+
+#[test]
+fn test_map_literal_missing_colon() {
+    let mut parser = Parser::new(Scanner::new("{\"a\" 1}", ScanError::new()));
+
+    assert!(parser.primary().is_err());
+    assert!(parser.error.get());
+}
+
+#[test]
+fn test_map_literal_cannot_start_a_statement() {
+    let parser = parse_statements("{\"a\": 1}[\"a\"] = 2;");
+
+    assert_eq!(
+        *parser.errors.borrow(),
+        vec![
+            "Error at {: '{' at the start of a statement is reserved for blocks. Wrap a map in parentheses."
+                .to_string()
+        ]
+    );
+    assert!(!parse_statements("({\"a\": 1})[\"a\"] = 2;").error.get());
+}
+
+#[test]
+fn test_power_binds_tighter_than_unary_minus() {
+    assert_eq!(
+        parse_expression("-2 ** 2"),
+        ("(- (** 2 2))".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("2 ** -1"),
+        ("(** 2 (- 1))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_power_is_right_associative() {
+    assert_eq!(
+        parse_expression("2 ** 3 ** 2"),
+        ("(** 2 (** 3 2))".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("2 * 3 ** 2"),
+        ("(* 2 (** 3 2))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_modulo_and_floor_division_share_factor_precedence() {
+    assert_eq!(
+        parse_expression("1 + 7 % 4 ~/ 2 * 3"),
+        ("(+ 1 (* (~/ (% 7 4) 2) 3))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_bitwise_precedence_follows_c() {
+    assert_eq!(
+        parse_expression("1 | 2 ^ 3 & 4"),
+        ("(| 1 (^ 2 (& 3 4)))".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("1 & 2 == 2"),
+        ("(& 1 (== 2 2))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_shift_sits_between_comparison_and_term() {
+    assert_eq!(
+        parse_expression("1 << 2 + 3 < 4 >> 1"),
+        ("(< (<< 1 (+ 2 3)) (>> 4 1))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_bitwise_not_is_unary() {
+    assert_eq!(
+        parse_expression("~1 & ~-2"),
+        ("(& (~ 1) (~ (- 2)))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_conditional_is_right_associative() {
+    assert_eq!(
+        parse_expression("1 ? 2 : 3 ? 4 : 5"),
+        ("(?: 1 2 (?: 3 4 5))".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("1 ? 2 ? 3 : 4 : 5"),
+        ("(?: 1 (?: 2 3 4) 5)".to_string(), false)
+    );
+}
+
+#[test]
+fn test_conditional_binds_looser_than_binary_operators() {
+    assert_eq!(
+        parse_expression("1 < 2 | 0 ? 3 + 4 : -5"),
+        ("(?: (| (< 1 2) 0) (+ 3 4) (- 5))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_conditional_as_assigned_value() {
+    assert_eq!(
+        parse_expression("[0][0] = true ? 1 : 2"),
+        ("(index= (list 0) 0 (?: true 1 2))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_conditional_missing_colon() {
+    let (printed, error) = parse_expression("true ? 1");
+
+    assert_eq!(printed, "true");
+    assert!(error);
+}
+
+#[test]
+fn test_conditional_missing_else_branch() {
+    let parser = parse_statements("print 1 ? 2;");
+
+    assert_eq!(
+        *parser.errors.borrow(),
+        vec!["Error at ;: Expect ':' after then branch of conditional.".to_string()]
+    );
+}
+
+#[test]
+fn test_comma_is_lowest_and_left_associative() {
+    assert_eq!(
+        parse_expression("1, 2 ? 3 : 4, 5"),
+        ("(, (, 1 (?: 2 3 4)) 5)".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("true ? 1, 2 : 3"),
+        ("(?: true (, 1 2) 3)".to_string(), false)
+    );
+}
+
+#[test]
+fn test_commas_still_separate_items() {
+    assert_eq!(
+        parse_expression("[1, (2, 3)]"),
+        ("(list 1 (group (, 2 3)))".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("{1: 2, 3: 4}"),
+        ("(map 1 2 3 4)".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("[].slice(0, 0)"),
+        ("(call (. (list) slice) 0 0)".to_string(), false)
+    );
+}
+
+#[test]
+fn test_compound_assignment() {
+    assert_eq!(
+        parse_expression("[1][0] += 2"),
+        ("(+= (index (list 1) 0) 2)".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("[1][0] *= [2][0] -= 3"),
+        (
+            "(*= (index (list 1) 0) (-= (index (list 2) 0) 3))".to_string(),
+            false
+        )
+    );
+}
+
+#[test]
+fn test_increment_and_decrement() {
+    assert_eq!(
+        parse_expression("++[1][0]"),
+        ("(++ (index (list 1) 0))".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("[1][0]--"),
+        ("(post-- (index (list 1) 0))".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("-[1][0]++"),
+        ("(- (post++ (index (list 1) 0)))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_prefix_update_binds_tighter_than_power() {
+    assert_eq!(
+        parse_expression("++[1][0] ** 2"),
+        ("(** (++ (index (list 1) 0)) 2)".to_string(), false)
+    );
+    assert_eq!(
+        parse_expression("-++[1][0]"),
+        ("(- (++ (index (list 1) 0)))".to_string(), false)
+    );
+}
+
+#[test]
+fn test_update_of_invalid_target() {
+    assert_eq!(parse_expression("1 += 2"), ("1".to_string(), true));
+    assert_eq!(parse_expression("++1"), ("1".to_string(), true));
+    assert_eq!(parse_expression("1--"), ("1".to_string(), true));
+}
+
+fn parse_statements(source: &str) -> Parser<Scanner<'_>> {
+    let mut parser = Parser::new(Scanner::new(source, ScanError::new()));
+    let statements = parser.parse();
+    assert_eq!(statements.len(), 1);
+    parser
+}
+
+#[test]
+fn test_match_statement() {
+    let mut parser = Parser::new(Scanner::new(
+        "match (1) { 1 or -2 or \"a\" => print 1; nil if true => print 2; _ => print 3; }",
+        ScanError::new(),
+    ));
+    let statements = parser.parse();
+
+    let num = |n: f64| Expr::Literal(Box::new(LiteralExpr::Num(n)));
+    let print = |n: f64| Stmt::Print(PrintStmt::new(num(n)));
+    assert_eq!(
+        statements,
+        vec![Stmt::Match(MatchStmt::new(
+            num(1.0),
+            vec![
+                MatchArm::new(
+                    vec![
+                        Pattern::Literal(LiteralExpr::Num(1.0)),
+                        Pattern::Literal(LiteralExpr::Num(-2.0)),
+                        Pattern::Literal(LiteralExpr::Str("a".into())),
+                    ],
+                    None,
+                    print(1.0),
+                ),
+                MatchArm::new(
+                    vec![Pattern::Literal(LiteralExpr::Nil)],
+                    Some(Expr::Literal(Box::new(LiteralExpr::Bool(true)))),
+                    print(2.0),
+                ),
+                MatchArm::new(vec![Pattern::Wildcard], None, print(3.0)),
+            ],
+        ))]
+    );
+    assert!(!parser.error.get());
+    assert!(parser.warnings.is_empty());
+}
+
+#[test]
+fn test_match_arm_after_wildcard_warns() {
+    let parser = parse_statements("match (1) { _ => 1; 2 => 2; 3 => 3; }");
+
+    assert!(!parser.error.get());
+    assert_eq!(
+        parser.warnings,
+        vec![
+            "[line 1] Warning at '2': Unreachable match arm after wildcard.".to_string(),
+            "[line 1] Warning at '3': Unreachable match arm after wildcard.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_match_guarded_wildcard_does_not_warn() {
+    let parser = parse_statements("match (1) { _ if false => 1; 2 or _ => 2; }");
+
+    assert!(parser.warnings.is_empty());
+}
+
+#[test]
+fn test_match_pattern_errors() {
+    assert!(parse_statements("match (1) { x => 1; }").error.get());
+    assert!(parse_statements("match (1) { 1 2; }").error.get());
+}
+
+#[test]
+fn test_match_invalid_patterns_are_skipped() {
+    let mut parser = Parser::new(Scanner::new(
+        "match (\"a1\") { x or \"a${1}\" => print 1; \"b\" => print 2; _ => print 3; }",
+        ScanError::new(),
+    ));
+    let statements = parser.parse();
+
+    let Stmt::Match(stmt) = &statements[0] else {
+        panic!("Expected a match statement");
+    };
+    assert_eq!(stmt.arms.len(), 3);
+    assert!(stmt.arms[0].patterns.is_empty());
+    assert_eq!(
+        *parser.errors.borrow(),
+        vec![
+            "Error at x: Binding patterns are not supported yet.".to_string(),
+            "Error at \"a${: Expect literal pattern or '_'.".to_string(),
+        ]
+    );
+    assert!(parser.warnings.is_empty());
+}
+
+#[test]
+fn test_syntax_errors_are_recorded_without_panicking() {
+    let mut parser = Parser::new(Scanner::new(
+        "print [1, 2; print ]; print 1;",
+        ScanError::new(),
+    ));
+    let statements = parser.parse();
+
+    assert_eq!(statements.len(), 3);
+    assert_eq!(
+        *parser.errors.borrow(),
+        vec![
+            "Error at ;: Expect ']' after list elements.".to_string(),
+            "Error at ]: Expect expression.".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_parser_makes_progress_after_an_error_that_consumes_nothing() {
+    let mut parser = Parser::new(Scanner::new(") print 1;", ScanError::new()));
+    let statements = parser.parse();
+
+    assert_eq!(statements.len(), 2);
+    assert_eq!(parser.errors.borrow().len(), 1);
+}
+
+#[test]
+fn test_match_arm_without_arrow_before_closing_brace() {
+    let parser = parse_statements("match (1) { x }");
+    assert_eq!(
+        *parser.errors.borrow(),
+        vec![
+            "Error at x: Binding patterns are not supported yet.".to_string(),
+            "Error at }: Expect '=>' after match pattern.".to_string(),
+        ]
+    );
+
+    let parser = parse_statements("match (1) { 1 }");
+    assert_eq!(
+        *parser.errors.borrow(),
+        vec!["Error at }: Expect '=>' after match pattern.".to_string()]
+    );
+}
+
+#[test]
+fn test_match_warning_names_line_and_token() {
+    let parser = parse_statements("match (5) {\n  _ => print 1;\n  3 => print 2;\n}");
+
+    assert_eq!(
+        parser.warnings,
+        vec!["[line 3] Warning at '3': Unreachable match arm after wildcard.".to_string()]
+    );
+}
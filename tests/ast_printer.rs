@@ -1,4 +1,8 @@
-use lox_rs::ast::expr::{BinaryExpr, Expr, GroupingExpr, LiteralExpr, UnaryExpr, Visitor};
+use lox_rs::ast::expr::{
+    BinaryExpr, CallExpr, CommaExpr, ConditionalExpr, Expr, GetExpr, GroupingExpr, IndexExpr,
+    IndexSetExpr, IndexUpdateExpr, InterpolationExpr, ListExpr, LiteralExpr, MapExpr, UnaryExpr,
+    Visitor,
+};
 use lox_rs::ast::printer::Printer;
 use lox_rs::ast::token::{Token, TokenType};
 
@@ -8,7 +12,7 @@ fn test_ast_printer() {
         left: Box::new(Expr::Unary(Box::new(UnaryExpr {
             operator: Token {
                 token_type: TokenType::Minus,
-                lexeme: "-".to_string(),
+                lexeme: "-",
                 literal: None,
                 line: 1,
             },
@@ -16,7 +20,7 @@ fn test_ast_printer() {
         }))),
         operator: Token {
             token_type: TokenType::Star,
-            lexeme: "*".to_string(),
+            lexeme: "*",
             literal: None,
             line: 1,
         },
@@ -33,7 +37,7 @@ fn test_ast_printer() {
 
 #[test]
 fn test_visit_literal_str() {
-    let expr = LiteralExpr::Str("Hello".to_string());
+    let expr = LiteralExpr::Str("Hello".into());
     let mut printer = Printer;
     let result = printer.visit_literal_expr(&expr);
     assert_eq!(result, "Hello");
@@ -70,3 +74,113 @@ fn test_visit_literal_nil() {
     let result = printer.visit_literal_expr(&expr);
     assert_eq!(result, "nil");
 }
+
+#[test]
+fn test_visit_interpolation_expr() {
+    let expr = Expr::Interpolation(Box::new(InterpolationExpr::new(vec![
+        Expr::Literal(Box::new(LiteralExpr::Str("Hello ".into()))),
+        Expr::Literal(Box::new(LiteralExpr::Num(1.0))),
+        Expr::Literal(Box::new(LiteralExpr::Str("!".into()))),
+    ])));
+    let mut printer = Printer;
+    let result = printer.print(&expr);
+    assert_eq!(result, "(interpolate Hello  1 !)");
+}
+
+#[test]
+fn test_visit_list_index_and_call_exprs() {
+    let num = |n: f64| Expr::Literal(Box::new(LiteralExpr::Num(n)));
+    let list = Expr::List(Box::new(ListExpr::new(vec![num(1.0), num(2.0)])));
+    let bracket = Token::new(TokenType::LeftBracket, "[", None, 1);
+    let mut printer = Printer;
+
+    let index = Expr::Index(Box::new(IndexExpr::new(
+        list.clone(),
+        bracket.clone(),
+        num(0.0),
+    )));
+    assert_eq!(printer.print(&index), "(index (list 1 2) 0)");
+
+    let set = Expr::IndexSet(Box::new(IndexSetExpr::new(
+        list.clone(),
+        bracket,
+        num(0.0),
+        num(3.0),
+    )));
+    assert_eq!(printer.print(&set), "(index= (list 1 2) 0 3)");
+
+    let get = Expr::Get(Box::new(GetExpr::new(
+        list,
+        Token::new(TokenType::Identifier, "push", None, 1),
+    )));
+    let call = Expr::Call(Box::new(CallExpr::new(
+        get,
+        Token::new(TokenType::RightParen, ")", None, 1),
+        vec![num(4.0)],
+    )));
+    assert_eq!(printer.print(&call), "(call (. (list 1 2) push) 4)");
+}
+
+#[test]
+fn test_visit_map_expr() {
+    let expr = Expr::Map(Box::new(MapExpr::new(
+        Token::new(TokenType::LeftBrace, "{", None, 1),
+        vec![
+            (
+                Expr::Literal(Box::new(LiteralExpr::Str("a".into()))),
+                Expr::Literal(Box::new(LiteralExpr::Num(1.0))),
+            ),
+            (
+                Expr::Literal(Box::new(LiteralExpr::Nil)),
+                Expr::Literal(Box::new(LiteralExpr::Bool(true))),
+            ),
+        ],
+    )));
+    let mut printer = Printer;
+    assert_eq!(printer.print(&expr), "(map a 1 nil true)");
+}
+
+#[test]
+fn test_visit_conditional_and_comma_exprs() {
+    let num = |n: f64| Expr::Literal(Box::new(LiteralExpr::Num(n)));
+    let conditional = Expr::Conditional(Box::new(ConditionalExpr::new(
+        Expr::Literal(Box::new(LiteralExpr::Bool(true))),
+        num(1.0),
+        num(2.0),
+    )));
+    let comma = Expr::Comma(Box::new(CommaExpr::new(num(0.0), conditional)));
+    let mut printer = Printer;
+    assert_eq!(printer.print(&comma), "(, 0 (?: true 1 2))");
+}
+
+#[test]
+fn test_visit_index_update_expr() {
+    let num = |n: f64| Expr::Literal(Box::new(LiteralExpr::Num(n)));
+    let target = IndexExpr::new(
+        Expr::List(Box::new(ListExpr::new(vec![num(1.0)]))),
+        Token::new(TokenType::LeftBracket, "[", None, 1),
+        num(0.0),
+    );
+    let update = |token_type, lexeme: &'static str, postfix| {
+        Expr::IndexUpdate(Box::new(IndexUpdateExpr::new(
+            target.clone(),
+            Token::new(token_type, lexeme, None, 1),
+            num(2.0),
+            postfix,
+        )))
+    };
+    let mut printer = Printer;
+
+    assert_eq!(
+        printer.print(&update(TokenType::SlashEqual, "/=", false)),
+        "(/= (index (list 1) 0) 2)"
+    );
+    assert_eq!(
+        printer.print(&update(TokenType::PlusPlus, "++", false)),
+        "(++ (index (list 1) 0))"
+    );
+    assert_eq!(
+        printer.print(&update(TokenType::MinusMinus, "--", true)),
+        "(post-- (index (list 1) 0))"
+    );
+}
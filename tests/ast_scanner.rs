@@ -28,6 +28,7 @@ fn test_keywords_present() {
     assert_eq!(KEYWORDS.get("for"), Some(&TokenType::For));
     assert_eq!(KEYWORDS.get("fun"), Some(&TokenType::Fun));
     assert_eq!(KEYWORDS.get("if"), Some(&TokenType::If));
+    assert_eq!(KEYWORDS.get("match"), Some(&TokenType::Match));
     assert_eq!(KEYWORDS.get("nil"), Some(&TokenType::Nil));
     assert_eq!(KEYWORDS.get("or"), Some(&TokenType::Or));
     assert_eq!(KEYWORDS.get("print"), Some(&TokenType::Print));
@@ -48,13 +49,13 @@ fn test_keywords_not_present() {
 
 #[test]
 fn test_keyword_count() {
-    assert_eq!(KEYWORDS.len(), 16);
+    assert_eq!(KEYWORDS.len(), 17);
 }
 
 #[test]
 fn test_scanner_initialization() {
     let source = "class".to_string();
-    let scanner = Scanner::new(source.clone(), ScanError::new());
+    let scanner = Scanner::new(&source, ScanError::new());
     assert_eq!(scanner.source, source);
     assert_eq!(scanner.tokens.len(), 0);
     assert_eq!(scanner.start, 0);
@@ -68,7 +69,7 @@ fn test_scanner_initialization() {
 
 #[test]
 fn test_scan_tokens_empty_input() {
-    let mut scanner = Scanner::new("".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("", ScanError::new());
     let tokens = scanner.scan_tokens();
     assert_eq!(tokens.len(), 1);
     assert_eq!(tokens[0].token_type, TokenType::Eof);
@@ -76,7 +77,7 @@ fn test_scan_tokens_empty_input() {
 
 #[test]
 fn test_scan_tokens_single_character() {
-    let mut scanner = Scanner::new("(".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("(", ScanError::new());
     let tokens = scanner.scan_tokens();
     assert_eq!(tokens.len(), 2);
     assert_eq!(tokens[0].token_type, TokenType::LeftParen);
@@ -85,7 +86,7 @@ fn test_scan_tokens_single_character() {
 
 #[test]
 fn test_scan_tokens_multiple_characters() {
-    let mut scanner = Scanner::new("(+)".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("(+)", ScanError::new());
     let tokens = scanner.scan_tokens();
     assert_eq!(tokens.len(), 4);
     assert_eq!(tokens[0].token_type, TokenType::LeftParen);
@@ -96,7 +97,7 @@ fn test_scan_tokens_multiple_characters() {
 
 #[test]
 fn test_scan_tokens_with_number() {
-    let mut scanner = Scanner::new("42".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("42", ScanError::new());
     let tokens = scanner.scan_tokens();
     assert_eq!(tokens.len(), 2);
     assert_eq!(tokens[0].token_type, TokenType::Number);
@@ -108,9 +109,63 @@ fn test_scan_tokens_with_number() {
     }
 }
 
+#[test]
+fn test_scanner_iterates_tokens_lazily() {
+    let mut scanner = Scanner::new("1 + 2", ScanError::new());
+
+    let token = scanner.next().unwrap();
+    assert_eq!(token.token_type, TokenType::Number);
+    assert_eq!(scanner.current, 1);
+
+    let token = scanner.next().unwrap();
+    assert_eq!(token.token_type, TokenType::Plus);
+    assert_eq!(scanner.current, 3);
+}
+
+#[test]
+fn test_scanner_yields_eof_once() {
+    let mut scanner = Scanner::new("", ScanError::new());
+
+    assert_eq!(scanner.next().unwrap().token_type, TokenType::Eof);
+    assert!(scanner.next().is_none());
+    assert!(scanner.next().is_none());
+}
+
+#[test]
+fn test_scanner_skips_whitespace_and_comments_between_tokens() {
+    let scanner = Scanner::new(
+        "  // comment
+  42  ",
+        ScanError::new(),
+    );
+    let tokens: Vec<_> = scanner.collect();
+
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].token_type, TokenType::Number);
+    assert_eq!(tokens[0].line, 2);
+    assert_eq!(tokens[1].token_type, TokenType::Eof);
+}
+
+#[test]
+fn test_scanner_borrows_source() {
+    let source = String::from("print 1;");
+    let scanner = Scanner::new(&source, ScanError::new());
+
+    assert!(std::ptr::eq(scanner.source, source.as_str()));
+}
+
+#[test]
+fn test_scanned_lexemes_borrow_source() {
+    let source = String::from("var lexeme_in_source = (1);");
+    let tokens = Scanner::new(&source, ScanError::new()).scan_tokens();
+
+    assert_eq!(tokens[1].lexeme, "lexeme_in_source");
+    assert!(std::ptr::eq(tokens[1].lexeme, &source[4..20]));
+}
+
 #[test]
 fn test_is_at_end_at_start() {
-    let scanner = Scanner::new("test".to_string(), ScanError::new());
+    let scanner = Scanner::new("test", ScanError::new());
     assert!(
         !scanner.is_at_end(),
         "Scanner should not be at the end initially"
@@ -119,7 +174,7 @@ fn test_is_at_end_at_start() {
 
 #[test]
 fn test_is_at_end_at_middle() {
-    let mut scanner = Scanner::new("test".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("test", ScanError::new());
     scanner.current = 2;
     assert!(
         !scanner.is_at_end(),
@@ -129,7 +184,7 @@ fn test_is_at_end_at_middle() {
 
 #[test]
 fn test_is_at_end_at_end() {
-    let mut scanner = Scanner::new("test".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("test", ScanError::new());
     scanner.current = 4;
     assert!(
         scanner.is_at_end(),
@@ -139,7 +194,7 @@ fn test_is_at_end_at_end() {
 
 #[test]
 fn test_is_at_end_beyond_end() {
-    let mut scanner = Scanner::new("test".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("test", ScanError::new());
     scanner.current = 5;
     assert!(
         scanner.is_at_end(),
@@ -149,7 +204,7 @@ fn test_is_at_end_beyond_end() {
 
 #[test]
 fn test_is_at_end_with_empty_string() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(
         scanner.is_at_end(),
         "Scanner should be at the end for an empty string"
@@ -158,9 +213,9 @@ fn test_is_at_end_with_empty_string() {
 
 #[test]
 fn test_scan_single_character_tokens() {
-    let source = "_(){},.-+;*=><!\"".to_string();
+    let source = "_(){},.-+;* = ><!\"".to_string();
     let len = source.len();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     for _ in 0..len {
         scanner.scan_token();
     }
@@ -193,12 +248,12 @@ fn test_scan_single_character_tokens() {
 fn test_scan_two_character_tokens() {
     let source = "!= == <= >=".to_string();
     let len = source.len();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     while scanner.current < len {
         scanner.scan_token();
     }
 
-    let expected_tokens = vec![
+    let expected_tokens = [
         TokenType::BangEqual,
         TokenType::EqualEqual,
         TokenType::LessEqual,
@@ -213,7 +268,7 @@ fn test_scan_two_character_tokens() {
 #[test]
 fn test_scan_slash_token() {
     let source = "/ // comment\n".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.scan_token();
     scanner.scan_token();
 
@@ -224,7 +279,7 @@ fn test_scan_slash_token() {
 #[test]
 fn test_scan_comment_token() {
     let source = "// comment\n".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.scan_token();
     scanner.scan_token();
 
@@ -235,7 +290,7 @@ fn test_scan_comment_token() {
 fn test_scan_whitespace_and_newline() {
     let source = " \n\t".to_string();
     let len = source.len();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     for _ in 0..len {
         scanner.scan_token();
     }
@@ -246,7 +301,7 @@ fn test_scan_whitespace_and_newline() {
 #[test]
 fn test_scan_unexpected_character() {
     let source = "@".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.scan_token();
 
     assert_eq!(scanner.tokens.len(), 0);
@@ -255,27 +310,27 @@ fn test_scan_unexpected_character() {
 
 #[test]
 fn test_is_alpha_with_lowercase_letters() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(scanner.is_alpha('a'));
     assert!(scanner.is_alpha('z'));
 }
 
 #[test]
 fn test_is_alpha_with_uppercase_letters() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(scanner.is_alpha('A'));
     assert!(scanner.is_alpha('Z'));
 }
 
 #[test]
 fn test_is_alpha_with_underscore() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(scanner.is_alpha('_'));
 }
 
 #[test]
 fn test_is_alpha_with_non_alpha_characters() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(!scanner.is_alpha('1'));
     assert!(!scanner.is_alpha(' '));
     assert!(!scanner.is_alpha('!'));
@@ -284,7 +339,7 @@ fn test_is_alpha_with_non_alpha_characters() {
 
 #[test]
 fn test_is_alpha_with_boundary_cases() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(!scanner.is_alpha('`')); // ASCII before 'a'
     assert!(!scanner.is_alpha('{')); // ASCII after 'z'
     assert!(!scanner.is_alpha('@')); // ASCII before 'A'
@@ -293,27 +348,27 @@ fn test_is_alpha_with_boundary_cases() {
 
 #[test]
 fn test_is_alpha_numeric_with_letters() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(scanner.is_alpha_numeric('a'));
     assert!(scanner.is_alpha_numeric('Z'));
 }
 
 #[test]
 fn test_is_alpha_numeric_with_underscore() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(scanner.is_alpha_numeric('_'));
 }
 
 #[test]
 fn test_is_alpha_numeric_with_digits() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(scanner.is_alpha_numeric('0'));
     assert!(scanner.is_alpha_numeric('9'));
 }
 
 #[test]
 fn test_is_alpha_numeric_with_non_alphanumeric_characters() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(!scanner.is_alpha_numeric(' '));
     assert!(!scanner.is_alpha_numeric('-'));
     assert!(!scanner.is_alpha_numeric('!'));
@@ -322,7 +377,7 @@ fn test_is_alpha_numeric_with_non_alphanumeric_characters() {
 
 #[test]
 fn test_is_alpha_numeric_with_boundary_cases() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(scanner.is_alpha_numeric('a'));
     assert!(scanner.is_alpha_numeric('Z'));
     assert!(scanner.is_alpha_numeric('0'));
@@ -336,7 +391,7 @@ fn test_is_alpha_numeric_with_boundary_cases() {
 #[test]
 fn test_identifier_as_keyword() {
     let source = "var".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.identifier();
 
     assert_eq!(scanner.tokens.len(), 1);
@@ -347,7 +402,7 @@ fn test_identifier_as_keyword() {
 #[test]
 fn test_identifier_as_regular_identifier() {
     let source = "my_var".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.identifier();
 
     assert_eq!(scanner.tokens.len(), 1);
@@ -358,7 +413,7 @@ fn test_identifier_as_regular_identifier() {
 #[test]
 fn test_identifier_as_identifier_with_embedded_number() {
     let source = "my_var_87".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.identifier();
 
     assert_eq!(scanner.tokens.len(), 1);
@@ -369,7 +424,7 @@ fn test_identifier_as_identifier_with_embedded_number() {
 #[test]
 fn test_scan_integer_number() {
     let source = "12345".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.number();
 
     assert_eq!(scanner.tokens.len(), 1);
@@ -381,7 +436,7 @@ fn test_scan_integer_number() {
 #[test]
 fn test_scan_float_number() {
     let source = "123.456".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.number();
 
     assert_eq!(scanner.tokens.len(), 1);
@@ -393,7 +448,7 @@ fn test_scan_float_number() {
 #[test]
 fn test_scan_number_with_trailing_dot() {
     let source = "123.".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.number();
 
     assert_eq!(scanner.tokens.len(), 1);
@@ -405,7 +460,7 @@ fn test_scan_number_with_trailing_dot() {
 #[test]
 fn test_scan_number_with_leading_dot() {
     let source = ".456".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
     scanner.number();
 
     assert_eq!(scanner.tokens.len(), 1);
@@ -416,7 +471,7 @@ fn test_scan_number_with_leading_dot() {
 
 #[test]
 fn test_is_digit_with_digits() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(scanner.is_digit('0'));
     assert!(scanner.is_digit('5'));
     assert!(scanner.is_digit('9'));
@@ -424,7 +479,7 @@ fn test_is_digit_with_digits() {
 
 #[test]
 fn test_is_digit_with_non_digits() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(!scanner.is_digit('a'));
     assert!(!scanner.is_digit('Z'));
     assert!(!scanner.is_digit(' '));
@@ -433,7 +488,7 @@ fn test_is_digit_with_non_digits() {
 
 #[test]
 fn test_is_digit_with_boundary_cases() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert!(scanner.is_digit('0')); // Lower boundary
     assert!(scanner.is_digit('9')); // Upper boundary
     assert!(!scanner.is_digit('/')); // Just before '0'
@@ -443,7 +498,8 @@ fn test_is_digit_with_boundary_cases() {
 #[test]
 fn test_string_literal() {
     let source = "\"hello world\"".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
+    scanner.advance();
     scanner.string();
 
     assert_eq!(scanner.tokens.len(), 1);
@@ -451,14 +507,15 @@ fn test_string_literal() {
     assert_eq!(scanner.tokens[0].lexeme, "\"hello world\"");
     assert_eq!(
         scanner.tokens[0].literal,
-        Some(LiteralExpr::Str("hello world".to_string()))
+        Some(LiteralExpr::Str("hello world".into()))
     );
 }
 
 #[test]
 fn test_multi_line_string_literal() {
     let source = "\"hello\nworld\"".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
+    scanner.advance();
     scanner.string();
 
     assert_eq!(scanner.tokens.len(), 1);
@@ -466,7 +523,7 @@ fn test_multi_line_string_literal() {
     assert_eq!(scanner.tokens[0].lexeme, "\"hello\nworld\"");
     assert_eq!(
         scanner.tokens[0].literal,
-        Some(LiteralExpr::Str("hello\nworld".to_string()))
+        Some(LiteralExpr::Str("hello\nworld".into()))
     );
     assert_eq!(scanner.line, 2);
 }
@@ -474,7 +531,8 @@ fn test_multi_line_string_literal() {
 #[test]
 fn test_unterminated_string_literal() {
     let source = "\"hello world".to_string();
-    let mut scanner = Scanner::new(source, ScanError::new());
+    let mut scanner = Scanner::new(&source, ScanError::new());
+    scanner.advance();
     scanner.string();
 
     assert!(scanner.error.detected());
@@ -483,87 +541,87 @@ fn test_unterminated_string_literal() {
 
 #[test]
 fn test_peek_at_start() {
-    let scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let scanner = Scanner::new("hello", ScanError::new());
     assert_eq!(scanner.peek(), 'h');
 }
 
 #[test]
 fn test_peek_at_middle() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 2;
     assert_eq!(scanner.peek(), 'l');
 }
 
 #[test]
 fn test_peek_at_end() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 5; // Position at the end of the string
     assert_eq!(scanner.peek(), '\0');
 }
 
 #[test]
 fn test_peek_beyond_end() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 10; // Position beyond the end of the string
     assert_eq!(scanner.peek(), '\0');
 }
 
 #[test]
 fn test_peek_with_empty_string() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert_eq!(scanner.peek(), '\0');
 }
 
 #[test]
 fn test_peek_next_at_start() {
-    let scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let scanner = Scanner::new("hello", ScanError::new());
     assert_eq!(scanner.peek_next(), 'e');
 }
 
 #[test]
 fn test_peek_next_in_middle() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 2;
     assert_eq!(scanner.peek_next(), 'l');
 }
 
 #[test]
 fn test_peek_next_at_end() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 4; // Position at the last character
     assert_eq!(scanner.peek_next(), '\0');
 }
 
 #[test]
 fn test_peek_next_beyond_end() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 5; // Position beyond the end of the string
     assert_eq!(scanner.peek_next(), '\0');
 }
 
 #[test]
 fn test_peek_next_with_empty_string() {
-    let scanner = Scanner::new("".to_string(), ScanError::new());
+    let scanner = Scanner::new("", ScanError::new());
     assert_eq!(scanner.peek_next(), '\0');
 }
 
 #[test]
 fn test_match_char_success() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     assert!(scanner.match_char('h'));
     assert_eq!(scanner.current, 1);
 }
 
 #[test]
 fn test_match_char_failure() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     assert!(!scanner.match_char('x'));
     assert_eq!(scanner.current, 0); // Should not advance
 }
 
 #[test]
 fn test_match_char_at_end() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 5; // Position at the end of the string
     assert!(!scanner.match_char('o'));
     assert_eq!(scanner.current, 5); // Should not advance
@@ -571,7 +629,7 @@ fn test_match_char_at_end() {
 
 #[test]
 fn test_match_char_advance_on_success() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     assert!(scanner.match_char('h'));
     assert_eq!(scanner.current, 1); // Should advance
     assert!(scanner.match_char('e'));
@@ -580,7 +638,7 @@ fn test_match_char_advance_on_success() {
 
 #[test]
 fn test_match_char_with_multibyte_characters() {
-    let mut scanner = Scanner::new("héllo".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("héllo", ScanError::new());
     assert!(scanner.match_char('h'));
     assert_eq!(scanner.current, 1); // Should advance by 1
     assert!(scanner.match_char('é'));
@@ -589,7 +647,7 @@ fn test_match_char_with_multibyte_characters() {
 
 #[test]
 fn test_advance_at_start() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     let ch = scanner.advance();
     assert_eq!(ch, 'h');
     assert_eq!(scanner.current, 1);
@@ -597,7 +655,7 @@ fn test_advance_at_start() {
 
 #[test]
 fn test_advance_in_middle() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 2;
     let ch = scanner.advance();
     assert_eq!(ch, 'l');
@@ -606,7 +664,7 @@ fn test_advance_in_middle() {
 
 #[test]
 fn test_advance_at_end() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 4; // Position at 'o'
     let ch = scanner.advance();
     assert_eq!(ch, 'o');
@@ -615,7 +673,7 @@ fn test_advance_at_end() {
 
 #[test]
 fn test_advance_with_multibyte_characters() {
-    let mut scanner = Scanner::new("héllo".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("héllo", ScanError::new());
     let ch = scanner.advance();
     assert_eq!(ch, 'h');
     assert_eq!(scanner.current, 1);
@@ -627,14 +685,14 @@ fn test_advance_with_multibyte_characters() {
 #[test]
 #[should_panic(expected = "unwrap")]
 fn test_advance_past_end() {
-    let mut scanner = Scanner::new("hello".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("hello", ScanError::new());
     scanner.current = 5; // Position beyond the end
     scanner.advance(); // This should panic
 }
 
 #[test]
 fn test_add_token_with_identifier() {
-    let mut scanner = Scanner::new("identifier".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("identifier", ScanError::new());
     scanner.current = 10; // Simulating the end of the identifier
     scanner.add_token(TokenType::Identifier);
 
@@ -646,7 +704,7 @@ fn test_add_token_with_identifier() {
 
 #[test]
 fn test_add_token_with_number() {
-    let mut scanner = Scanner::new("123".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("123", ScanError::new());
     scanner.current = 3; // Simulating the end of the number
     scanner.add_token(TokenType::Number);
 
@@ -658,7 +716,7 @@ fn test_add_token_with_number() {
 
 #[test]
 fn test_add_token_with_plus() {
-    let mut scanner = Scanner::new("+".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("+", ScanError::new());
     scanner.current = 1; // Simulating the end of the plus sign
     scanner.add_token(TokenType::Plus);
 
@@ -670,7 +728,7 @@ fn test_add_token_with_plus() {
 
 #[test]
 fn test_add_token_with_minus() {
-    let mut scanner = Scanner::new("-".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("-", ScanError::new());
     scanner.current = 1; // Simulating the end of the minus sign
     scanner.add_token(TokenType::Minus);
 
@@ -682,25 +740,22 @@ fn test_add_token_with_minus() {
 
 #[test]
 fn test_add_token_with_literal_string() {
-    let mut scanner = Scanner::new("\"hello\"".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("\"hello\"", ScanError::new());
     scanner.current = 7; // Simulating the end of the string literal
-    scanner.add_token_with_literal(
-        TokenType::String,
-        Some(LiteralExpr::Str("hello".to_string())),
-    );
+    scanner.add_token_with_literal(TokenType::String, Some(LiteralExpr::Str("hello".into())));
 
     assert_eq!(scanner.tokens.len(), 1);
     assert_eq!(scanner.tokens[0].token_type, TokenType::String);
     assert_eq!(scanner.tokens[0].lexeme, "\"hello\"");
     assert_eq!(
         scanner.tokens[0].literal,
-        Some(LiteralExpr::Str("hello".to_string()))
+        Some(LiteralExpr::Str("hello".into()))
     );
 }
 
 #[test]
 fn test_add_token_with_literal_number() {
-    let mut scanner = Scanner::new("123".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("123", ScanError::new());
     scanner.current = 3; // Simulating the end of the number
     scanner.add_token_with_literal(TokenType::Number, Some(LiteralExpr::Num(123.0)));
 
@@ -712,7 +767,7 @@ fn test_add_token_with_literal_number() {
 
 #[test]
 fn test_add_token_with_literal_none() {
-    let mut scanner = Scanner::new("+".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("+", ScanError::new());
     scanner.current = 1; // Simulating the end of the plus sign
     scanner.add_token_with_literal(TokenType::Plus, None);
 
@@ -724,7 +779,7 @@ fn test_add_token_with_literal_none() {
 
 #[test]
 fn test_add_token_with_literal_identifier() {
-    let mut scanner = Scanner::new("variable".to_string(), ScanError::new());
+    let mut scanner = Scanner::new("variable", ScanError::new());
     scanner.current = 8; // Simulating the end of the identifier
     scanner.add_token_with_literal(TokenType::Identifier, None);
 
@@ -733,3 +788,673 @@ fn test_add_token_with_literal_identifier() {
     assert_eq!(scanner.tokens[0].lexeme, "variable");
     assert_eq!(scanner.tokens[0].literal, None);
 }
+
+#[test]
+fn test_peek_next_after_multibyte_character() {
+    let scanner = Scanner::new("é1", ScanError::new());
+    assert_eq!(scanner.peek(), 'é');
+    assert_eq!(scanner.peek_next(), '1');
+}
+
+#[test]
+fn test_peek_next_before_multibyte_character() {
+    let scanner = Scanner::new("1é", ScanError::new());
+    assert_eq!(scanner.peek_next(), 'é');
+}
+
+#[test]
+fn test_number_followed_by_dot_and_multibyte_character() {
+    let mut scanner = Scanner::new("1.é", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert_eq!(tokens[0].token_type, TokenType::Number);
+    assert_eq!(tokens[0].lexeme, "1");
+    assert_eq!(tokens[1].token_type, TokenType::Dot);
+    assert_eq!(tokens[2].token_type, TokenType::Identifier);
+    assert_eq!(tokens[2].lexeme, "é");
+}
+
+#[test]
+fn test_is_alpha_with_unicode_letters() {
+    let scanner = Scanner::new("", ScanError::new());
+    assert!(scanner.is_alpha('é'));
+    assert!(scanner.is_alpha('Ж'));
+    assert!(scanner.is_alpha('日'));
+}
+
+#[test]
+fn test_is_alpha_rejects_xid_continue_only_characters() {
+    let scanner = Scanner::new("", ScanError::new());
+    assert!(!scanner.is_alpha('\u{0301}')); // combining acute accent
+    assert!(!scanner.is_alpha('٣')); // Arabic-Indic digit three
+    assert!(!scanner.is_alpha('😀'));
+}
+
+#[test]
+fn test_is_alpha_numeric_with_unicode_characters() {
+    let scanner = Scanner::new("", ScanError::new());
+    assert!(scanner.is_alpha_numeric('é'));
+    assert!(scanner.is_alpha_numeric('\u{0301}'));
+    assert!(scanner.is_alpha_numeric('٣'));
+    assert!(!scanner.is_alpha_numeric('😀'));
+    assert!(!scanner.is_alpha_numeric('²'));
+}
+
+#[test]
+fn test_unicode_identifier() {
+    let mut scanner = Scanner::new("café naïve_2 日本", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens[0].token_type, TokenType::Identifier);
+    assert_eq!(tokens[0].lexeme, "café");
+    assert_eq!(tokens[1].lexeme, "naïve_2");
+    assert_eq!(tokens[2].lexeme, "日本");
+    assert!(!scanner.error.detected());
+}
+
+#[test]
+fn test_identifier_starting_with_combining_mark_is_an_error() {
+    let mut scanner = Scanner::new("\u{0301}a", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert!(scanner.error.detected());
+    assert_eq!(tokens[0].token_type, TokenType::Identifier);
+    assert_eq!(tokens[0].lexeme, "a");
+}
+
+#[test]
+fn test_unexpected_multibyte_character() {
+    let mut scanner = Scanner::new("😀 1", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert!(scanner.error.detected());
+    assert_eq!(tokens[0].token_type, TokenType::Number);
+}
+
+#[test]
+fn test_multibyte_characters_in_string_literal() {
+    let mut scanner = Scanner::new("\"héllo 😀\"", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert_eq!(tokens[0].token_type, TokenType::String);
+    assert_eq!(tokens[0].literal, Some(LiteralExpr::Str("héllo 😀".into())));
+}
+
+/// Small xorshift generator so the fuzz tests are reproducible without
+/// pulling in a dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+fn scan_without_panicking(source: &str) {
+    let mut scanner = Scanner::new(source, ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert_eq!(tokens.last().unwrap().token_type, TokenType::Eof);
+    assert!(scanner.current >= source.len());
+}
+
+#[test]
+fn test_fuzz_scanner_with_lox_like_input() {
+    let pieces = [
+        "(", ")", "{", "}", ",", ".", "-", "+", ";", "*", "/", "!", "=", "<", ">", "\"", "//",
+        "\n", " ", "\t", "1", "2.5", ".", "var", "x", "é", "日", "😀", "\u{0301}", "_", "\0", "0x",
+        "0b1", "1e", "1_0", "\\", "\\u{", "r\"", "r#\"", "#", "/*", "*/",
+    ];
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..2_000 {
+        let mut source = String::new();
+        for _ in 0..rng.below(24) {
+            source.push_str(pieces[rng.below(pieces.len())]);
+        }
+        scan_without_panicking(&source);
+    }
+}
+
+#[test]
+fn test_fuzz_scanner_with_arbitrary_characters() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..2_000 {
+        let source: String = (0..rng.below(32))
+            .filter_map(|_| char::from_u32((rng.next() % 0x11_0000) as u32))
+            .collect();
+        scan_without_panicking(&source);
+    }
+}
+
+fn scan_number(source: &str) -> (Vec<lox_rs::ast::token::Token<'_>>, bool) {
+    let mut scanner = Scanner::new(source, ScanError::new());
+    let tokens = scanner.scan_tokens();
+    (tokens, scanner.error.detected())
+}
+
+fn assert_number(source: &str, expected: f64) {
+    let (tokens, error) = scan_number(source);
+
+    assert!(!error, "unexpected scan error for {}", source);
+    assert_eq!(tokens.len(), 2, "expected a single token for {}", source);
+    assert_eq!(tokens[0].token_type, TokenType::Number);
+    assert_eq!(tokens[0].lexeme, source);
+    assert_eq!(tokens[0].literal, Some(LiteralExpr::Num(expected)));
+}
+
+fn assert_number_error(source: &str) {
+    let (tokens, error) = scan_number(source);
+
+    assert!(error, "expected a scan error for {}", source);
+    assert!(tokens.iter().all(|t| t.token_type != TokenType::Number));
+}
+
+#[test]
+fn test_scan_hexadecimal_number() {
+    assert_number("0xFF", 255.0);
+    assert_number("0Xff", 255.0);
+    assert_number("0x0", 0.0);
+}
+
+#[test]
+fn test_scan_binary_number() {
+    assert_number("0b1010", 10.0);
+    assert_number("0B1", 1.0);
+}
+
+#[test]
+fn test_scan_octal_number() {
+    assert_number("0o17", 15.0);
+    assert_number("0O777", 511.0);
+}
+
+#[test]
+fn test_scan_number_with_digit_separators() {
+    assert_number("1_000_000", 1_000_000.0);
+    assert_number("0xFF_FF", 65535.0);
+    assert_number("0b1010_1010", 170.0);
+    assert_number("1_000.000_1", 1000.0001);
+}
+
+#[test]
+fn test_scan_number_with_exponent() {
+    assert_number("1.5e-3", 0.0015);
+    assert_number("1e10", 1e10);
+    assert_number("2E+2", 200.0);
+    assert_number("1_0e1_0", 10e10);
+}
+
+#[test]
+fn test_scan_decimal_with_leading_zero() {
+    assert_number("007", 7.0);
+}
+
+#[test]
+fn test_scan_malformed_radix_prefix() {
+    assert_number_error("0x");
+    assert_number_error("0b");
+    assert_number_error("0o;");
+}
+
+#[test]
+fn test_scan_malformed_exponent() {
+    assert_number_error("1e");
+    assert_number_error("1e+");
+    assert_number_error("2.5E-x");
+}
+
+#[test]
+fn test_scan_invalid_digit_in_radix_literal() {
+    assert_number_error("0b102");
+    assert_number_error("0o8");
+    assert_number_error("0xFG");
+}
+
+#[test]
+fn test_scan_misplaced_digit_separator() {
+    assert_number_error("1_");
+    assert_number_error("1__0");
+    assert_number_error("0x_FF");
+    assert_number_error("1_.5");
+    assert_number_error("1.5_e3");
+}
+
+#[test]
+fn test_scan_radix_literal_too_large() {
+    assert_number_error("0x1_0000_0000_0000_0000");
+}
+
+#[test]
+fn test_scan_number_followed_by_operator() {
+    let (tokens, error) = scan_number("0x10+1e2");
+
+    assert!(!error);
+    assert_eq!(tokens[0].literal, Some(LiteralExpr::Num(16.0)));
+    assert_eq!(tokens[1].token_type, TokenType::Plus);
+    assert_eq!(tokens[2].literal, Some(LiteralExpr::Num(100.0)));
+}
+
+fn scan_string(source: &str) -> (Option<LiteralExpr>, bool) {
+    let mut scanner = Scanner::new(source, ScanError::new());
+    let tokens = scanner.scan_tokens();
+    let literal = tokens
+        .iter()
+        .find(|t| t.token_type == TokenType::String)
+        .and_then(|t| t.literal.clone());
+    (literal, scanner.error.detected())
+}
+
+#[test]
+fn test_scan_empty_string() {
+    let (literal, error) = scan_string("\"\"");
+
+    assert!(!error);
+    assert_eq!(literal, Some(LiteralExpr::Str("".into())));
+}
+
+#[test]
+fn test_scan_empty_string_followed_by_tokens() {
+    let mut scanner = Scanner::new("\"\" + \"a\"", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert!(!scanner.error.detected());
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens[0].literal, Some(LiteralExpr::Str("".into())));
+    assert_eq!(tokens[1].token_type, TokenType::Plus);
+    assert_eq!(tokens[2].literal, Some(LiteralExpr::Str("a".into())));
+}
+
+#[test]
+fn test_scan_string_escape_sequences() {
+    let (literal, error) = scan_string(r#""a\nb\tc\rd\\e\"f\0""#);
+
+    assert!(!error);
+    assert_eq!(literal, Some(LiteralExpr::Str("a\nb\tc\rd\\e\"f\0".into())));
+}
+
+#[test]
+fn test_scan_string_keeps_lexeme_as_written() {
+    let mut scanner = Scanner::new(r#""a\n""#, ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert_eq!(tokens[0].lexeme, r#""a\n""#);
+}
+
+#[test]
+fn test_scan_string_unicode_escape() {
+    let (literal, error) = scan_string(r#""\u{1F600} \u{e9}\u{41}""#);
+
+    assert!(!error);
+    assert_eq!(literal, Some(LiteralExpr::Str("😀 éA".into())));
+}
+
+#[test]
+fn test_scan_string_unknown_escape() {
+    let (_, error) = scan_string(r#""\q""#);
+    assert!(error);
+}
+
+#[test]
+fn test_scan_string_invalid_unicode_escapes() {
+    assert!(scan_string(r#""\u41""#).1);
+    assert!(scan_string(r#""\u{}""#).1);
+    assert!(scan_string(r#""\u{41""#).1);
+    assert!(scan_string(r#""\u{1234567}""#).1);
+    assert!(scan_string(r#""\u{D800}""#).1);
+    assert!(scan_string(r#""\u{110000}""#).1);
+}
+
+#[test]
+fn test_scan_string_with_trailing_backslash_is_unterminated() {
+    let (literal, error) = scan_string("\"abc\\");
+
+    assert!(error);
+    assert_eq!(literal, None);
+}
+
+#[test]
+fn test_scan_raw_string_keeps_backslashes() {
+    let (literal, error) = scan_string(r#"r"C:\temp\new""#);
+
+    assert!(!error);
+    assert_eq!(literal, Some(LiteralExpr::Str(r"C:\temp\new".into())));
+}
+
+#[test]
+fn test_scan_raw_string_with_hashes() {
+    let (literal, error) = scan_string(r###"r#"say "hi" \d+"#"###);
+
+    assert!(!error);
+    assert_eq!(literal, Some(LiteralExpr::Str(r#"say "hi" \d+"#.into())));
+}
+
+#[test]
+fn test_scan_multi_line_raw_string() {
+    let mut scanner = Scanner::new("r\"line one\nline \\two\"\nx", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert!(!scanner.error.detected());
+    assert_eq!(
+        tokens[0].literal,
+        Some(LiteralExpr::Str("line one\nline \\two".into()))
+    );
+    assert_eq!(tokens[0].line, 2);
+    assert_eq!(tokens[1].lexeme, "x");
+    assert_eq!(tokens[1].line, 3);
+}
+
+#[test]
+fn test_scan_unterminated_raw_string() {
+    assert!(scan_string("r\"abc").1);
+    assert!(scan_string("r#\"abc\"").1);
+}
+
+#[test]
+fn test_identifier_starting_with_r_is_not_a_raw_string() {
+    let mut scanner = Scanner::new("r rx", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert_eq!(tokens[0].token_type, TokenType::Identifier);
+    assert_eq!(tokens[0].lexeme, "r");
+    assert_eq!(tokens[1].lexeme, "rx");
+}
+
+#[test]
+fn test_scan_string_interpolation() {
+    let mut scanner = Scanner::new("\"Hello ${name}, you are ${age + 1}\"", ScanError::new());
+    let tokens = scanner.scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert!(!scanner.error.detected());
+    assert_eq!(
+        types,
+        vec![
+            TokenType::Interpolation,
+            TokenType::Identifier,
+            TokenType::Interpolation,
+            TokenType::Identifier,
+            TokenType::Plus,
+            TokenType::Number,
+            TokenType::String,
+            TokenType::Eof,
+        ]
+    );
+    assert_eq!(tokens[0].literal, Some(LiteralExpr::Str("Hello ".into())));
+    assert_eq!(
+        tokens[2].literal,
+        Some(LiteralExpr::Str(", you are ".into()))
+    );
+    assert_eq!(tokens[6].literal, Some(LiteralExpr::Str("".into())));
+}
+
+#[test]
+fn test_scan_interpolation_with_nested_braces_and_strings() {
+    let mut scanner = Scanner::new("\"a ${ {} } b ${\"c ${d} e\"} f\"", ScanError::new());
+    let tokens = scanner.scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert!(!scanner.error.detected());
+    assert_eq!(
+        types,
+        vec![
+            TokenType::Interpolation,
+            TokenType::LeftBrace,
+            TokenType::RightBrace,
+            TokenType::Interpolation,
+            TokenType::Interpolation,
+            TokenType::Identifier,
+            TokenType::String,
+            TokenType::String,
+            TokenType::Eof,
+        ]
+    );
+    assert_eq!(tokens[3].literal, Some(LiteralExpr::Str(" b ".into())));
+    assert_eq!(tokens[4].literal, Some(LiteralExpr::Str("c ".into())));
+    assert_eq!(tokens[6].literal, Some(LiteralExpr::Str(" e".into())));
+    assert_eq!(tokens[7].literal, Some(LiteralExpr::Str(" f".into())));
+}
+
+#[test]
+fn test_scan_escaped_interpolation() {
+    let (literal, error) = scan_string(r#""cost: \${x} $5""#);
+
+    assert!(!error);
+    assert_eq!(literal, Some(LiteralExpr::Str("cost: ${x} $5".into())));
+}
+
+#[test]
+fn test_scan_raw_string_does_not_interpolate() {
+    let (literal, error) = scan_string(r#"r"${x}""#);
+
+    assert!(!error);
+    assert_eq!(literal, Some(LiteralExpr::Str("${x}".into())));
+}
+
+#[test]
+fn test_scan_unterminated_interpolation() {
+    let mut scanner = Scanner::new("\"a ${b", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert!(scanner.error.detected());
+    assert_eq!(tokens.last().unwrap().token_type, TokenType::Eof);
+}
+
+#[test]
+fn test_scan_block_comment() {
+    let mut scanner = Scanner::new("1 /* comment */ + 2", ScanError::new());
+    let tokens = scanner.scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert!(!scanner.error.detected());
+    assert_eq!(
+        types,
+        vec![
+            TokenType::Number,
+            TokenType::Plus,
+            TokenType::Number,
+            TokenType::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_scan_nested_block_comment() {
+    let mut scanner = Scanner::new("/* a /* b */ c */ 1 /**/ /***/", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert!(!scanner.error.detected());
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].token_type, TokenType::Number);
+}
+
+#[test]
+fn test_scan_block_comment_counts_lines() {
+    let mut scanner = Scanner::new("/* one\ntwo /* three\n */\n*/ x", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert!(!scanner.error.detected());
+    assert_eq!(tokens[0].lexeme, "x");
+    assert_eq!(tokens[0].line, 4);
+}
+
+#[test]
+fn test_scan_block_comment_does_not_end_string_or_line_comment() {
+    let mut scanner = Scanner::new("\"/* not a comment */\" // /* \n 1", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert!(!scanner.error.detected());
+    assert_eq!(
+        tokens[0].literal,
+        Some(LiteralExpr::Str("/* not a comment */".into()))
+    );
+    assert_eq!(tokens[1].token_type, TokenType::Number);
+}
+
+#[test]
+fn test_scan_unterminated_block_comment() {
+    let mut scanner = Scanner::new("1\n/* a\n/* b */\n", ScanError::new());
+    let tokens = scanner.scan_tokens();
+
+    assert!(scanner.error.detected());
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(scanner.line, 4);
+}
+
+#[test]
+fn test_scan_star_slash_outside_comment() {
+    let mut scanner = Scanner::new("2 */ 1", ScanError::new());
+    let tokens = scanner.scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert_eq!(
+        types,
+        vec![
+            TokenType::Number,
+            TokenType::Star,
+            TokenType::Slash,
+            TokenType::Number,
+            TokenType::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_scan_brackets() {
+    let tokens = Scanner::new("[1, 2][0]", ScanError::new()).scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert_eq!(
+        types,
+        vec![
+            TokenType::LeftBracket,
+            TokenType::Number,
+            TokenType::Comma,
+            TokenType::Number,
+            TokenType::RightBracket,
+            TokenType::LeftBracket,
+            TokenType::Number,
+            TokenType::RightBracket,
+            TokenType::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_scan_map_literal_tokens() {
+    let tokens = Scanner::new("{\"a\": 1}", ScanError::new()).scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert_eq!(
+        types,
+        vec![
+            TokenType::LeftBrace,
+            TokenType::String,
+            TokenType::Colon,
+            TokenType::Number,
+            TokenType::RightBrace,
+            TokenType::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_scan_arithmetic_operators() {
+    let tokens = Scanner::new("* ** % ~/ / // comment", ScanError::new()).scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert_eq!(
+        types,
+        vec![
+            TokenType::Star,
+            TokenType::StarStar,
+            TokenType::Percent,
+            TokenType::TildeSlash,
+            TokenType::Slash,
+            TokenType::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_scan_star_star_star() {
+    let tokens = Scanner::new("***", ScanError::new()).scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert_eq!(
+        types,
+        vec![TokenType::StarStar, TokenType::Star, TokenType::Eof]
+    );
+}
+
+#[test]
+fn test_scan_bitwise_operators() {
+    let tokens = Scanner::new("& | ^ ~ ~/ << <= < >> >= >", ScanError::new()).scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert_eq!(
+        types,
+        vec![
+            TokenType::Ampersand,
+            TokenType::Pipe,
+            TokenType::Caret,
+            TokenType::Tilde,
+            TokenType::TildeSlash,
+            TokenType::LessLess,
+            TokenType::LessEqual,
+            TokenType::Less,
+            TokenType::GreaterGreater,
+            TokenType::GreaterEqual,
+            TokenType::Greater,
+            TokenType::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_scan_compound_assignment_operators() {
+    let tokens = Scanner::new("+= -= *= /= ++ -- **", ScanError::new()).scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert_eq!(
+        types,
+        vec![
+            TokenType::PlusEqual,
+            TokenType::MinusEqual,
+            TokenType::StarEqual,
+            TokenType::SlashEqual,
+            TokenType::PlusPlus,
+            TokenType::MinusMinus,
+            TokenType::StarStar,
+            TokenType::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_scan_match_tokens() {
+    let tokens = Scanner::new("match _ => = > ==", ScanError::new()).scan_tokens();
+    let types: Vec<TokenType> = tokens.iter().map(|t| t.token_type).collect();
+
+    assert_eq!(
+        types,
+        vec![
+            TokenType::Match,
+            TokenType::Identifier,
+            TokenType::FatArrow,
+            TokenType::Equal,
+            TokenType::Greater,
+            TokenType::EqualEqual,
+            TokenType::Eof,
+        ]
+    );
+}
@@ -0,0 +1,39 @@
+use std::collections::HashSet;
+
+use lox_rs::ast::interner::Symbol;
+use lox_rs::ast::string::Str;
+
+#[test]
+fn test_interned_strings_share_the_symbol() {
+    let a = Str::from(Symbol::intern("shared"));
+    let b = Str::from(Symbol::intern("shared"));
+
+    assert_eq!(a, b);
+    assert!(std::ptr::eq(a.as_str(), b.as_str()));
+}
+
+#[test]
+fn test_interned_and_owned_strings_compare_by_contents() {
+    let interned = Str::from(Symbol::intern("mixed"));
+    let owned = Str::from("mixed".to_string());
+
+    assert_eq!(interned, owned);
+    assert_eq!(owned, interned);
+    assert_ne!(owned, Str::from("other"));
+}
+
+#[test]
+fn test_interned_and_owned_strings_hash_the_same() {
+    let mut set = HashSet::new();
+    set.insert(Str::from(Symbol::intern("key")));
+
+    assert!(set.contains(&Str::from("key")));
+}
+
+#[test]
+fn test_str_display_and_deref() {
+    let value = Str::from("text");
+
+    assert_eq!(value.to_string(), "text");
+    assert_eq!(value.len(), 4);
+}
@@ -1,11 +1,12 @@
 use lox_rs::ast::expr::LiteralExpr;
+use lox_rs::ast::interner::Symbol;
 use lox_rs::ast::token::{Token, TokenType};
 
 macro_rules! test_create_token {
     ($name:ident, $token_type:expr, $lexeme:expr, $literal:expr, $line:expr) => {
         #[test]
         fn $name() {
-            let token = Token::new($token_type, $lexeme.to_string(), $literal, $line);
+            let token = Token::new($token_type, $lexeme, $literal, $line);
 
             assert_eq!(token.token_type, $token_type);
             assert_eq!(token.lexeme, $lexeme);
@@ -154,14 +155,14 @@ test_create_token!(
     test_create_token_with_identifier_token_type,
     TokenType::Identifier,
     "identifier",
-    Some(LiteralExpr::Str("x".to_string())),
+    Some(LiteralExpr::Str("x".into())),
     20
 );
 test_create_token!(
     test_create_token_with_string_token_type,
     TokenType::String,
     "string",
-    Some(LiteralExpr::Str("x".to_string())),
+    Some(LiteralExpr::Str("x".into())),
     21
 );
 test_create_token!(
@@ -297,7 +298,7 @@ macro_rules! test_token_display {
     ($name:ident, $token_type:expr, $lexeme:expr, $literal:expr, $line:expr, $expected_output:expr) => {
         #[test]
         fn $name() {
-            let token = Token::new($token_type, $lexeme.to_string(), $literal, $line);
+            let token = Token::new($token_type, $lexeme, $literal, $line);
             assert_eq!(token.to_string(), $expected_output);
         }
     };
@@ -316,7 +317,7 @@ test_token_display!(
     test_token_display_with_string_literal,
     TokenType::String,
     "\"hello\"",
-    Some(LiteralExpr::Str("hello".to_string())),
+    Some(LiteralExpr::Str("hello".into())),
     2,
     "String \"hello\" Some(Str(\"hello\"))"
 );
@@ -408,3 +409,28 @@ test_display_token_type!(test_display_var, TokenType::Var, "Var");
 test_display_token_type!(test_display_while, TokenType::While, "While");
 
 test_display_token_type!(test_display_eof, TokenType::Eof, "Eof");
+
+#[test]
+fn test_to_static_uses_fixed_text_for_operators_and_keywords() {
+    let source = String::from("+= while");
+    let plus_equal = Token::new(TokenType::PlusEqual, &source[0..2], None, 1).to_static();
+    let keyword = Token::new(TokenType::While, &source[3..], None, 1).to_static();
+
+    assert_eq!(plus_equal.lexeme, "+=");
+    assert_eq!(keyword.lexeme, "while");
+}
+
+#[test]
+fn test_to_static_interns_identifiers() {
+    let source = String::from("static_identifier");
+    let token = Token::new(TokenType::Identifier, &source, None, 3);
+
+    let token = token.to_static();
+
+    assert_eq!(token.lexeme, "static_identifier");
+    assert_eq!(token.line, 3);
+    assert!(std::ptr::eq(
+        token.lexeme,
+        Symbol::intern("static_identifier").as_str()
+    ));
+}
@@ -1,7 +1,9 @@
 use lox_rs::ast::expr::{
-    BinaryExpr, Expr, GroupingExpr, LiteralExpr, UnaryExpr, VariableExpr, Visitor,
+    BinaryExpr, CallExpr, CommaExpr, ConditionalExpr, Expr, GetExpr, GroupingExpr, IndexExpr,
+    IndexSetExpr, IndexUpdateExpr, InterpolationExpr, ListExpr, LiteralExpr, MapExpr, UnaryExpr,
+    VariableExpr, Visitor,
 };
-use lox_rs::ast::stmt::{ExpressionStmt, PrintStmt, VarStmt};
+use lox_rs::ast::stmt::{ExpressionStmt, MatchStmt, PrintStmt, VarStmt};
 use lox_rs::ast::token::{Token, TokenType};
 
 struct MockVisitor {
@@ -29,25 +31,80 @@ impl Visitor<String> for MockVisitor {
         "UnaryExpr".to_string()
     }
 
-    fn visit_expression_stmt(&mut self, stmt: &ExpressionStmt) -> String {
+    fn visit_expression_stmt(&mut self, _stmt: &ExpressionStmt) -> String {
         self.log.push("Visited ExpressionStmt".to_string());
         "ExpressionStmt".to_string()
     }
 
-    fn visit_print_stmt(&mut self, stmt: &PrintStmt) -> String {
+    fn visit_print_stmt(&mut self, _stmt: &PrintStmt) -> String {
         self.log.push("Visited PrintStmt".to_string());
         "PrintStmt".to_string()
     }
 
-    fn visit_var_stmt(&mut self, stmt: &VarStmt) -> String {
+    fn visit_var_stmt(&mut self, _stmt: &VarStmt) -> String {
         self.log.push("Visited VarStmt".to_string());
         "VarStmt".to_string()
     }
 
-    fn visit_variable_expr(&mut self, expr: &VariableExpr) -> String {
+    fn visit_variable_expr(&mut self, _expr: &VariableExpr) -> String {
         self.log.push("Visited VariableExpr".to_string());
         "VariableExpr".to_string()
     }
+
+    fn visit_interpolation_expr(&mut self, _expr: &InterpolationExpr) -> String {
+        self.log.push("Visited InterpolationExpr".to_string());
+        "InterpolationExpr".to_string()
+    }
+
+    fn visit_list_expr(&mut self, _expr: &ListExpr) -> String {
+        self.log.push("Visited ListExpr".to_string());
+        "ListExpr".to_string()
+    }
+
+    fn visit_index_expr(&mut self, _expr: &IndexExpr) -> String {
+        self.log.push("Visited IndexExpr".to_string());
+        "IndexExpr".to_string()
+    }
+
+    fn visit_index_set_expr(&mut self, _expr: &IndexSetExpr) -> String {
+        self.log.push("Visited IndexSetExpr".to_string());
+        "IndexSetExpr".to_string()
+    }
+
+    fn visit_get_expr(&mut self, _expr: &GetExpr) -> String {
+        self.log.push("Visited GetExpr".to_string());
+        "GetExpr".to_string()
+    }
+
+    fn visit_call_expr(&mut self, _expr: &CallExpr) -> String {
+        self.log.push("Visited CallExpr".to_string());
+        "CallExpr".to_string()
+    }
+
+    fn visit_map_expr(&mut self, _expr: &MapExpr) -> String {
+        self.log.push("Visited MapExpr".to_string());
+        "MapExpr".to_string()
+    }
+
+    fn visit_conditional_expr(&mut self, _expr: &ConditionalExpr) -> String {
+        self.log.push("Visited ConditionalExpr".to_string());
+        "ConditionalExpr".to_string()
+    }
+
+    fn visit_comma_expr(&mut self, _expr: &CommaExpr) -> String {
+        self.log.push("Visited CommaExpr".to_string());
+        "CommaExpr".to_string()
+    }
+
+    fn visit_index_update_expr(&mut self, _expr: &IndexUpdateExpr) -> String {
+        self.log.push("Visited IndexUpdateExpr".to_string());
+        "IndexUpdateExpr".to_string()
+    }
+
+    fn visit_match_stmt(&mut self, _stmt: &MatchStmt) -> String {
+        self.log.push("Visited MatchStmt".to_string());
+        "MatchStmt".to_string()
+    }
 }
 
 #[test]
@@ -56,7 +113,7 @@ fn test_visit_binary_expr() {
         left: Box::new(Expr::Literal(Box::new(LiteralExpr::Num(1.0)))),
         operator: Token {
             token_type: TokenType::Plus,
-            lexeme: "+".to_string(),
+            lexeme: "+",
             literal: None,
             line: 1,
         },
@@ -93,7 +150,7 @@ fn test_visit_unary_expr() {
     let expr = UnaryExpr {
         operator: Token {
             token_type: TokenType::Minus,
-            lexeme: "-".to_string(),
+            lexeme: "-",
             literal: None,
             line: 1,
         },
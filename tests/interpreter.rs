@@ -1,15 +1,54 @@
+use std::rc::Rc;
+
 use lox_rs::{
     ast::{
-        expr::{BinaryExpr, Expr, GroupingExpr, LiteralExpr, UnaryExpr},
+        callable::Callable,
+        expr::{BinaryExpr, Expr, InterpolationExpr, LiteralExpr, Visitor},
+        interner::Symbol,
+        list::{List, ListMethod},
         object::Object,
+        parser::Parser,
+        scanner::{ScanError, Scanner},
+        string::Str,
         token::{Token, TokenType},
     },
     interpreter::{Interpreter, RuntimeError},
 };
 
+fn evaluate(source: &str) -> Result<Object, RuntimeError> {
+    let mut parser = Parser::new(Scanner::new(source, ScanError::new()));
+    let expr = parser.expression();
+    Interpreter.evaluate(expr)
+}
+
+fn execute(source: &str) -> Result<(), RuntimeError> {
+    let mut parser = Parser::new(Scanner::new(source, ScanError::new()));
+    for statement in parser.parse() {
+        Interpreter.execute(statement)?;
+    }
+    Ok(())
+}
+
+fn error_message(source: &str) -> String {
+    evaluate(source).unwrap_err().message
+}
+
+fn call_method(
+    list: &Rc<List>,
+    name: &'static str,
+    arguments: Vec<Object>,
+) -> Result<Object, RuntimeError> {
+    let method = ListMethod::new(
+        Rc::clone(list),
+        Token::new(TokenType::Identifier, name, None, 1),
+    );
+    assert_eq!(method.arity(), arguments.len());
+    method.call(&mut Interpreter, arguments)
+}
+
 #[test]
 fn test_runtime_error_creation() {
-    let token = Token::new(TokenType::Identifier, "test".to_string(), None, 1);
+    let token = Token::new(TokenType::Identifier, "test", None, 1);
 
     let message = "An error occurred.".to_string();
     let error = RuntimeError::new(token.clone(), message.clone());
@@ -20,12 +59,7 @@ fn test_runtime_error_creation() {
 
 #[test]
 fn test_runtime_error_message_content() {
-    let token = Token::new(
-        TokenType::Number,
-        "42".to_string(),
-        Some(LiteralExpr::Num(42.0)),
-        2,
-    );
+    let token = Token::new(TokenType::Number, "42", Some(LiteralExpr::Num(42.0)), 2);
 
     let message = "Invalid number format.".to_string();
     let error = RuntimeError::new(token.clone(), message.clone());
@@ -45,7 +79,7 @@ fn test_runtime_error_message_content() {
 
 // #[test]
 // fn test_interpreter_literal_string() {
-//     let expr = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let expr = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let mut interpreter = Interpreter;
 //     let result = interpreter.interpret(&expr);
 //     assert_eq!(result.type_name, std::any::type_name::<String>());
@@ -82,8 +116,8 @@ fn test_runtime_error_message_content() {
 
 // #[test]
 // fn test_interpreter_binary_addition_strings() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
-//     let right = Expr::Literal(Box::new(LiteralExpr::Str(" world".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
+//     let right = Expr::Literal(Box::new(LiteralExpr::Str(" world".into())));
 //     let operator = Token::new(TokenType::Plus, "+".to_string(), None, 1);
 
 //     let expr = Expr::Binary(Box::new(BinaryExpr {
@@ -107,7 +141,7 @@ fn test_runtime_error_message_content() {
 //     expected = "Operands Some(\"hello\"), None must be matching types for the Plus operation"
 // )]
 // fn test_interpreter_binary_addition_mismatch() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let right = Expr::Literal(Box::new(LiteralExpr::Num(2.0)));
 //     let operator = Token::new(TokenType::Plus, "+".to_string(), None, 1);
 
@@ -143,7 +177,7 @@ fn test_runtime_error_message_content() {
 // #[test]
 // #[should_panic(expected = "Operands must be numbers for the Minus operation")]
 // fn test_interpreter_binary_subtraction_mismatch() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let right = Expr::Literal(Box::new(LiteralExpr::Num(1.0)));
 //     let operator = Token::new(TokenType::Minus, "-".to_string(), None, 1);
 
@@ -179,7 +213,7 @@ fn test_runtime_error_message_content() {
 // #[test]
 // #[should_panic(expected = "Operands must be numbers for the Slash operation")]
 // fn test_interpreter_binary_division_mismatch() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let right = Expr::Literal(Box::new(LiteralExpr::Num(1.0)));
 //     let operator = Token::new(TokenType::Slash, "/".to_string(), None, 1);
 
@@ -215,7 +249,7 @@ fn test_runtime_error_message_content() {
 // #[test]
 // #[should_panic(expected = "Operands must be numbers for the Star operation")]
 // fn test_interpreter_binary_multiplication_mismatch() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let right = Expr::Literal(Box::new(LiteralExpr::Num(1.0)));
 //     let operator = Token::new(TokenType::Star, "*".to_string(), None, 1);
 
@@ -232,7 +266,7 @@ fn test_runtime_error_message_content() {
 // #[test]
 // #[should_panic(expected = "Unknown binary expression operator LeftParen")]
 // fn test_interpreter_binary_unknown_token_type() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let right = Expr::Literal(Box::new(LiteralExpr::Num(1.0)));
 //     let operator = Token::new(TokenType::LeftParen, "(".to_string(), None, 1);
 
@@ -363,7 +397,7 @@ fn test_runtime_error_message_content() {
 // #[test]
 // #[should_panic(expected = "Operands must be numbers for the Greater operation")]
 // fn test_interpreter_binary_greater_than_panic_not_numbers() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let right = Expr::Literal(Box::new(LiteralExpr::Num(2.0)));
 //     let operator = Token::new(TokenType::Greater, ">".to_string(), None, 1);
 
@@ -440,7 +474,7 @@ fn test_runtime_error_message_content() {
 // #[test]
 // #[should_panic(expected = "Operands must be numbers for the GreaterEqual operation")]
 // fn test_interpreter_binary_greater_than_equal_panic_not_numbers() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let right = Expr::Literal(Box::new(LiteralExpr::Num(2.0)));
 //     let operator = Token::new(TokenType::GreaterEqual, ">=".to_string(), None, 1);
 
@@ -498,7 +532,7 @@ fn test_runtime_error_message_content() {
 // #[test]
 // #[should_panic(expected = "Operands must be numbers for the Less operation")]
 // fn test_interpreter_binary_less_than_panic_not_numbers() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let right = Expr::Literal(Box::new(LiteralExpr::Num(2.0)));
 //     let operator = Token::new(TokenType::Less, "<".to_string(), None, 1);
 
@@ -575,7 +609,7 @@ fn test_runtime_error_message_content() {
 // #[test]
 // #[should_panic(expected = "Operands must be numbers for the LessEqual operation")]
 // fn test_interpreter_binary_less_than_equal_panic_not_numbers() {
-//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let left = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let right = Expr::Literal(Box::new(LiteralExpr::Num(2.0)));
 //     let operator = Token::new(TokenType::LessEqual, "<=".to_string(), None, 1);
 
@@ -596,56 +630,56 @@ fn test_runtime_error_message_content() {
 fn test_is_truthy_none() {
     let obj = Object::new(None::<()>);
     let interpreter = Interpreter;
-    assert_eq!(interpreter.is_truthy(&obj), false);
+    assert!(!interpreter.is_truthy(&obj));
 }
 
 #[test]
 fn test_is_truthy_true_bool() {
     let obj = Object::new(true);
     let interpreter = Interpreter;
-    assert_eq!(interpreter.is_truthy(&obj), true);
+    assert!(interpreter.is_truthy(&obj));
 }
 
 #[test]
 fn test_is_truthy_false_bool() {
     let obj = Object::new(false);
     let interpreter = Interpreter;
-    assert_eq!(interpreter.is_truthy(&obj), false);
+    assert!(!interpreter.is_truthy(&obj));
 }
 
 #[test]
 fn test_is_truthy_string() {
-    let obj = Object::new("hello".to_string());
+    let obj = Object::new(Str::from("hello"));
     let interpreter = Interpreter;
-    assert_eq!(interpreter.is_truthy(&obj), true);
+    assert!(interpreter.is_truthy(&obj));
 }
 
 #[test]
 fn test_is_truthy_empty_string() {
-    let obj = Object::new("".to_string());
+    let obj = Object::new(Str::from(""));
     let interpreter = Interpreter;
-    assert_eq!(interpreter.is_truthy(&obj), false);
+    assert!(!interpreter.is_truthy(&obj));
 }
 
 #[test]
 fn test_is_truthy_number() {
     let obj = Object::new(42.0);
     let interpreter = Interpreter;
-    assert_eq!(interpreter.is_truthy(&obj), true);
+    assert!(interpreter.is_truthy(&obj));
 }
 
 #[test]
 fn test_is_truthy_number_zero() {
     let obj = Object::new(0.0);
     let interpreter = Interpreter;
-    assert_eq!(interpreter.is_truthy(&obj), false);
+    assert!(!interpreter.is_truthy(&obj));
 }
 
 #[test]
 fn test_is_truthy_catchall() {
     let obj = Object::new(LiteralExpr::Nil);
     let interpreter = Interpreter;
-    assert_eq!(interpreter.is_truthy(&obj), true);
+    assert!(interpreter.is_truthy(&obj));
 }
 
 #[test]
@@ -666,20 +700,55 @@ fn test_is_equal_f64_not_equal() {
 
 #[test]
 fn test_is_equal_string_equal() {
-    let obj1 = Object::new("hello".to_string());
-    let obj2 = Object::new("hello".to_string());
+    let obj1 = Object::new(Str::from("hello"));
+    let obj2 = Object::new(Str::from("hello"));
     let interpreter = Interpreter;
     assert!(interpreter.is_equal(&obj1, &obj2));
 }
 
 #[test]
 fn test_is_equal_string_not_equal() {
-    let obj1 = Object::new("hello".to_string());
-    let obj2 = Object::new("world".to_string());
+    let obj1 = Object::new(Str::from("hello"));
+    let obj2 = Object::new(Str::from("world"));
     let interpreter = Interpreter;
     assert!(!interpreter.is_equal(&obj1, &obj2));
 }
 
+#[test]
+fn test_visit_literal_expr_string() {
+    let mut interpreter = Interpreter;
+    let result = interpreter
+        .visit_literal_expr(&LiteralExpr::Str("hello".into()))
+        .unwrap();
+    assert_eq!(result.get_value::<Str>().map(|s| s.as_str()), Some("hello"));
+}
+
+#[test]
+fn test_visit_literal_expr_string_keeps_the_symbol() {
+    let symbol = Symbol::intern("constant");
+    let mut interpreter = Interpreter;
+    let result = interpreter
+        .visit_literal_expr(&LiteralExpr::Str(symbol))
+        .unwrap();
+    assert!(matches!(result.get_value::<Str>(), Some(Str::Interned(s)) if *s == symbol));
+}
+
+#[test]
+fn test_visit_binary_expr_concatenation_is_not_interned() {
+    let expr = BinaryExpr::new(
+        Expr::Literal(Box::new(LiteralExpr::Str("foo".into()))),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Expr::Literal(Box::new(LiteralExpr::Str("bar".into()))),
+    );
+    let mut interpreter = Interpreter;
+    let result = interpreter.visit_binary_expr(&expr).unwrap();
+    assert_eq!(
+        result.get_value::<Str>().map(|s| s.as_str()),
+        Some("foobar")
+    );
+    assert!(matches!(result.get_value::<Str>(), Some(Str::Owned(_))));
+}
+
 #[test]
 fn test_is_equal_bool_equal() {
     let obj1 = Object::new(true);
@@ -699,14 +768,14 @@ fn test_is_equal_bool_not_equal() {
 #[test]
 fn test_is_equal_different_types() {
     let obj1 = Object::new(42.0);
-    let obj2 = Object::new("42".to_string());
+    let obj2 = Object::new(Str::from("42"));
     let interpreter = Interpreter;
     assert!(!interpreter.is_equal(&obj1, &obj2));
 }
 
 // #[test]
 // fn test_interpret_string_literal() {
-//     let literal_expr = Expr::Literal(Box::new(LiteralExpr::Str("hello".to_string())));
+//     let literal_expr = Expr::Literal(Box::new(LiteralExpr::Str("hello".into())));
 //     let mut interpreter = Interpreter;
 //     let result = interpreter.interpret(&literal_expr);
 //     assert_eq!(result.type_name, std::any::type_name::<String>());
@@ -830,3 +899,504 @@ fn test_is_equal_different_types() {
 //     let mut interpreter = Interpreter;
 //     interpreter.interpret(&expr);
 // }
+
+#[test]
+fn test_visit_interpolation_expr_formats_values_like_print() {
+    let expr = InterpolationExpr::new(vec![
+        Expr::Literal(Box::new(LiteralExpr::Str("n=".into()))),
+        Expr::Literal(Box::new(LiteralExpr::Num(2.5))),
+        Expr::Literal(Box::new(LiteralExpr::Str(", b=".into()))),
+        Expr::Literal(Box::new(LiteralExpr::Bool(true))),
+        Expr::Literal(Box::new(LiteralExpr::Str(", s=".into()))),
+        Expr::Literal(Box::new(LiteralExpr::Str("hi".into()))),
+        Expr::Literal(Box::new(LiteralExpr::Str("".into()))),
+    ]);
+    let mut interpreter = Interpreter;
+    let result = interpreter.visit_interpolation_expr(&expr).unwrap();
+    assert_eq!(
+        result.get_value::<Str>().map(|s| s.as_str()),
+        Some("n=2.5, b=true, s=hi")
+    );
+    assert!(matches!(result.get_value::<Str>(), Some(Str::Owned(_))));
+}
+
+#[test]
+fn test_visit_interpolation_expr_evaluates_embedded_expressions() {
+    let sum = Expr::Binary(Box::new(BinaryExpr::new(
+        Expr::Literal(Box::new(LiteralExpr::Num(40.0))),
+        Token::new(TokenType::Plus, "+", None, 1),
+        Expr::Literal(Box::new(LiteralExpr::Num(2.0))),
+    )));
+    let expr = InterpolationExpr::new(vec![
+        Expr::Literal(Box::new(LiteralExpr::Str("answer: ".into()))),
+        sum,
+        Expr::Literal(Box::new(LiteralExpr::Str("".into()))),
+    ]);
+    let mut interpreter = Interpreter;
+    let result = interpreter.visit_interpolation_expr(&expr).unwrap();
+    assert_eq!(result.to_string(), "answer: 42");
+}
+
+#[test]
+fn test_nil_literal_evaluates_to_nil() {
+    let result = evaluate("nil").unwrap();
+    assert!(result.is::<Option<()>>());
+    assert_eq!(result.to_string(), "nil");
+}
+
+#[test]
+fn test_type_errors_are_runtime_errors() {
+    assert_eq!(error_message("-\"a\""), "Operand must be a number.");
+    assert_eq!(error_message("1 < \"a\""), "Operands must be numbers.");
+    assert_eq!(
+        error_message("1 + \"a\""),
+        "Operands must be two numbers or two strings."
+    );
+}
+
+#[test]
+fn test_list_literal_prints_its_elements() {
+    let result = evaluate("[1, \"two\", [true, nil], 2.5]").unwrap();
+    assert_eq!(result.to_string(), "[1, \"two\", [true, nil], 2.5]");
+    assert_eq!(evaluate("[]").unwrap().to_string(), "[]");
+}
+
+#[test]
+fn test_list_indexing() {
+    assert_eq!(evaluate("[10, 20, 30][1]").unwrap().to_string(), "20");
+    assert_eq!(evaluate("[[1, 2], [3, 4]][1][0]").unwrap().to_string(), "3");
+}
+
+#[test]
+fn test_list_index_errors() {
+    assert_eq!(error_message("[1, 2][2]"), "List index 2 is out of range.");
+    assert_eq!(
+        error_message("[1, 2][-1]"),
+        "List index -1 is out of range."
+    );
+    assert_eq!(
+        error_message("[1, 2][0.5]"),
+        "List index must be an integer."
+    );
+    assert_eq!(
+        error_message("[1, 2][\"0\"]"),
+        "List index must be a number."
+    );
+    assert_eq!(
+        error_message("\"ab\"[0]"),
+        "Only lists and maps can be indexed."
+    );
+}
+
+#[test]
+fn test_list_index_error_reports_bracket_line() {
+    let error = evaluate("[1]\n[3]").unwrap_err();
+    assert_eq!(error.token.token_type, TokenType::LeftBracket);
+    assert_eq!(error.token.line, 2);
+}
+
+#[test]
+fn test_list_index_assignment() {
+    assert_eq!(evaluate("[1, 2][0] = 5").unwrap().to_string(), "5");
+    assert_eq!(
+        error_message("[1, 2][2] = 5"),
+        "List index 2 is out of range."
+    );
+
+    let list = Rc::new(List::new(vec![Object::new(1.0), Object::new(2.0)]));
+    let bracket = Token::new(TokenType::LeftBracket, "[", None, 1);
+    list.set(&bracket, &Object::new(1.0), Object::new(5.0))
+        .unwrap();
+    assert_eq!(list.to_string(), "[1, 5]");
+}
+
+#[test]
+fn test_list_methods_through_call_expressions() {
+    assert_eq!(evaluate("[1, 2, 3].len()").unwrap().to_string(), "3");
+    assert_eq!(evaluate("[1, 2, 3].pop()").unwrap().to_string(), "3");
+    assert_eq!(evaluate("[1, 2, 3].remove(0)").unwrap().to_string(), "1");
+    assert_eq!(
+        evaluate("[1, 2, 3].slice(1, 3)").unwrap().to_string(),
+        "[2, 3]"
+    );
+    assert_eq!(evaluate("[].push(1)").unwrap().to_string(), "nil");
+}
+
+#[test]
+fn test_list_methods_mutate_the_list() {
+    let list = Rc::new(List::default());
+
+    call_method(&list, "push", vec![Object::new(1.0)]).unwrap();
+    call_method(&list, "push", vec![Object::new(3.0)]).unwrap();
+    call_method(&list, "insert", vec![Object::new(1.0), Object::new(2.0)]).unwrap();
+    call_method(&list, "insert", vec![Object::new(3.0), Object::new(4.0)]).unwrap();
+    assert_eq!(list.to_string(), "[1, 2, 3, 4]");
+
+    let removed = call_method(&list, "remove", vec![Object::new(0.0)]).unwrap();
+    assert_eq!(removed.to_string(), "1");
+    let popped = call_method(&list, "pop", vec![]).unwrap();
+    assert_eq!(popped.to_string(), "4");
+    assert_eq!(list.to_string(), "[2, 3]");
+
+    let len = call_method(&list, "len", vec![]).unwrap();
+    assert_eq!(len.get_value::<f64>(), Some(&2.0));
+}
+
+#[test]
+fn test_list_slice_is_a_copy() {
+    let list = Rc::new(List::new(vec![Object::new(1.0), Object::new(2.0)]));
+
+    let slice = call_method(&list, "slice", vec![Object::new(0.0), Object::new(2.0)]).unwrap();
+    call_method(&list, "push", vec![Object::new(3.0)]).unwrap();
+
+    assert_eq!(slice.to_string(), "[1, 2]");
+    assert_eq!(list.to_string(), "[1, 2, 3]");
+}
+
+#[test]
+fn test_list_method_errors() {
+    assert_eq!(error_message("[].pop()"), "Can't pop from an empty list.");
+    assert_eq!(
+        error_message("[1].remove(1)"),
+        "List index 1 is out of range."
+    );
+    assert_eq!(
+        error_message("[1].insert(2, 0)"),
+        "List index 2 is out of range."
+    );
+    assert_eq!(
+        error_message("[1, 2].slice(2, 1)"),
+        "Slice start must not be after its end."
+    );
+    assert_eq!(
+        error_message("[1].push()"),
+        "Expected 1 arguments but got 0."
+    );
+    assert_eq!(error_message("[1].shift()"), "Undefined property 'shift'.");
+    assert_eq!(
+        error_message("\"a\".len()"),
+        "Only lists and maps have properties."
+    );
+    assert_eq!(
+        error_message("\"a\"()"),
+        "Can only call functions and classes."
+    );
+}
+
+#[test]
+fn test_cloned_list_objects_share_elements() {
+    let object = evaluate("[1]").unwrap();
+    let copy = object.clone();
+
+    let list = object.get_value::<Rc<List>>().unwrap();
+    call_method(list, "push", vec![Object::new(2.0)]).unwrap();
+
+    assert_eq!(copy.to_string(), "[1, 2]");
+    assert!(Interpreter.is_equal(&object, &copy));
+    assert!(!Interpreter.is_equal(&object, &evaluate("[1, 2]").unwrap()));
+}
+
+#[test]
+fn test_list_containing_itself_prints() {
+    let list = Rc::new(List::new(vec![Object::new(1.0)]));
+    call_method(&list, "push", vec![Object::new(Rc::clone(&list))]).unwrap();
+
+    assert_eq!(list.to_string(), "[1, [...]]");
+}
+
+#[test]
+fn test_nil_equals_nil() {
+    assert_eq!(evaluate("nil == nil").unwrap().to_string(), "true");
+    assert_eq!(evaluate("nil == false").unwrap().to_string(), "false");
+}
+
+#[test]
+fn test_map_literal_prints_its_entries() {
+    let result = evaluate("{\"a\": 1, 2: [true], nil: {}}").unwrap();
+    assert_eq!(result.to_string(), "{\"a\": 1, 2: [true], nil: {}}");
+}
+
+#[test]
+fn test_collections_quote_string_elements() {
+    let result = evaluate("[\"a\", \"1\", 1]").unwrap();
+    assert_eq!(result.to_string(), "[\"a\", \"1\", 1]");
+
+    let result = evaluate("[\"say \\\"hi\\\"\\n\", \"\\${x}\", \"\\\\\"]").unwrap();
+    assert_eq!(
+        result.to_string(),
+        "[\"say \\\"hi\\\"\\n\", \"\\${x}\", \"\\\\\"]"
+    );
+}
+
+#[test]
+fn test_string_prints_unquoted_outside_collections() {
+    let result = evaluate("\"a\"").unwrap();
+    assert_eq!(result.to_string(), "a");
+}
+
+#[test]
+fn test_map_literal_later_duplicate_key_wins() {
+    let result = evaluate("{\"a\": 1, \"b\": 2, \"a\": 3}").unwrap();
+    assert_eq!(result.to_string(), "{\"a\": 3, \"b\": 2}");
+}
+
+#[test]
+fn test_map_indexing_uses_runtime_equality() {
+    assert_eq!(
+        evaluate("{\"a\" + \"b\": 1}[\"ab\"]").unwrap().to_string(),
+        "1"
+    );
+    assert_eq!(evaluate("{0: \"zero\"}[-0]").unwrap().to_string(), "zero");
+    assert_eq!(evaluate("{1: \"one\"}[2 - 1]").unwrap().to_string(), "one");
+    assert_eq!(evaluate("{true: 1, nil: 2}[nil]").unwrap().to_string(), "2");
+}
+
+#[test]
+fn test_map_index_assignment() {
+    assert_eq!(evaluate("{}[\"a\"] = 5").unwrap().to_string(), "5");
+}
+
+#[test]
+fn test_map_errors() {
+    assert_eq!(error_message("{\"a\": 1}[\"b\"]"), "Undefined key 'b'.");
+    assert_eq!(
+        error_message("{[1]: 1}"),
+        "Map keys must be strings, numbers, booleans or nil."
+    );
+    assert_eq!(
+        error_message("{}[{}]"),
+        "Map keys must be strings, numbers, booleans or nil."
+    );
+    assert_eq!(
+        error_message("{}[[]] = 1"),
+        "Map keys must be strings, numbers, booleans or nil."
+    );
+    assert_eq!(error_message("{}.size()"), "Undefined property 'size'.");
+    assert_eq!(error_message("{}.remove(1)"), "Undefined key '1'.");
+}
+
+#[test]
+fn test_map_methods() {
+    let map = "{\"a\": 1, \"b\": 2}";
+    let call = |method: &str| {
+        evaluate(&format!("{}.{}", map, method))
+            .unwrap()
+            .to_string()
+    };
+
+    assert_eq!(call("keys()"), "[\"a\", \"b\"]");
+    assert_eq!(call("values()"), "[1, 2]");
+    assert_eq!(call("len()"), "2");
+    assert_eq!(call("has(\"a\")"), "true");
+    assert_eq!(call("has(\"c\")"), "false");
+    assert_eq!(call("remove(\"b\")"), "2");
+}
+
+#[test]
+fn test_cloned_map_objects_share_entries() {
+    let object = evaluate("{}").unwrap();
+    let copy = object.clone();
+
+    assert!(Interpreter.is_equal(&object, &copy));
+    assert!(!Interpreter.is_equal(&object, &evaluate("{}").unwrap()));
+}
+
+fn number(source: &str) -> f64 {
+    *evaluate(source).unwrap().get_value::<f64>().unwrap()
+}
+
+#[test]
+fn test_modulo_is_floored() {
+    assert_eq!(number("7 % 3"), 1.0);
+    assert_eq!(number("-7 % 3"), 2.0);
+    assert_eq!(number("7 % -3"), -2.0);
+    assert_eq!(number("-7 % -3"), -1.0);
+    assert_eq!(number("5.5 % 2"), 1.5);
+}
+
+#[test]
+fn test_floor_division() {
+    assert_eq!(number("7 ~/ 2"), 3.0);
+    assert_eq!(number("-7 ~/ 2"), -4.0);
+    assert_eq!(number("7.5 ~/ -2"), -4.0);
+}
+
+#[test]
+fn test_exponentiation() {
+    assert_eq!(number("2 ** 10"), 1024.0);
+    assert_eq!(number("2 ** 3 ** 2"), 512.0);
+    assert_eq!(number("-2 ** 2"), -4.0);
+    assert_eq!(number("(-2) ** 2"), 4.0);
+    assert_eq!(number("4 ** -0.5"), 0.5);
+}
+
+#[test]
+fn test_division_by_zero() {
+    assert_eq!(error_message("1 % 0"), "Division by zero.");
+    assert_eq!(error_message("1 ~/ 0"), "Division by zero.");
+    assert_eq!(number("1 / 0"), f64::INFINITY);
+}
+
+#[test]
+fn test_arithmetic_operators_require_numbers() {
+    assert_eq!(error_message("\"a\" % 2"), "Operands must be numbers.");
+    assert_eq!(error_message("2 ~/ \"a\""), "Operands must be numbers.");
+    assert_eq!(error_message("2 ** nil"), "Operands must be numbers.");
+}
+
+#[test]
+fn test_bitwise_operators() {
+    assert_eq!(number("12 & 10"), 8.0);
+    assert_eq!(number("12 | 10"), 14.0);
+    assert_eq!(number("12 ^ 10"), 6.0);
+    assert_eq!(number("~5"), -6.0);
+    assert_eq!(number("-1 & 255"), 255.0);
+}
+
+#[test]
+fn test_shift_operators() {
+    assert_eq!(number("1 << 10"), 1024.0);
+    assert_eq!(number("-3 << 2"), -12.0);
+    assert_eq!(number("1024 >> 3"), 128.0);
+    assert_eq!(number("-16 >> 2"), -4.0);
+    assert_eq!(number("-1 >> 100"), -1.0);
+    assert_eq!(number("0 << 100"), 0.0);
+    assert_eq!(number("1 << 52"), 4503599627370496.0);
+}
+
+#[test]
+fn test_bitwise_operands_must_be_safe_integers() {
+    let message = "Operands must be integers within the 53-bit safe range.";
+    assert_eq!(error_message("1.5 & 1"), message);
+    assert_eq!(error_message("1 | 9007199254740992"), message);
+    assert_eq!(error_message("1 << 0.5"), message);
+    assert_eq!(error_message("(1 / 0) ^ 1"), message);
+    assert_eq!(error_message("(0 / 0) ^ 1"), message);
+    assert_eq!(error_message("\"a\" & 1"), "Operands must be numbers.");
+    // `==` binds tighter than `&`, as in C.
+    assert_eq!(error_message("6 & 3 == 3"), "Operands must be numbers.");
+    assert_eq!(
+        error_message("~0.5"),
+        "Operand must be an integer within the 53-bit safe range."
+    );
+    assert_eq!(error_message("~\"a\""), "Operand must be a number.");
+}
+
+#[test]
+fn test_shift_errors() {
+    assert_eq!(
+        error_message("1 << -1"),
+        "Shift amount must not be negative."
+    );
+    assert_eq!(
+        error_message("1 << 53"),
+        "Shift result exceeds the 53-bit safe range."
+    );
+    assert_eq!(
+        error_message("1 << 64"),
+        "Shift result exceeds the 53-bit safe range."
+    );
+}
+
+#[test]
+fn test_conditional_evaluates_only_the_chosen_branch() {
+    assert_eq!(number("true ? 1 : [][0]"), 1.0);
+    assert_eq!(number("nil ? [][0] : 2"), 2.0);
+    assert_eq!(number("0 ? 1 : 2 ? 3 : 4"), 3.0);
+    assert_eq!(
+        error_message("1 ? [][0] : 2"),
+        "List index 0 is out of range."
+    );
+}
+
+#[test]
+fn test_comma_yields_its_right_operand() {
+    assert_eq!(number("1, 2, 3"), 3.0);
+    assert_eq!(evaluate("[(1, 2)]").unwrap().to_string(), "[2]");
+    assert_eq!(error_message("[][0], 1"), "List index 0 is out of range.");
+}
+
+#[test]
+fn test_compound_assignment_yields_the_new_value() {
+    assert_eq!(number("[1][0] += 2"), 3.0);
+    assert_eq!(number("[5][0] -= 0.5"), 4.5);
+    assert_eq!(number("{\"n\": 3}[\"n\"] *= 4"), 12.0);
+    assert_eq!(number("[9][0] /= 2"), 4.5);
+    assert_eq!(evaluate("[\"a\"][0] += \"b\"").unwrap().to_string(), "ab");
+}
+
+#[test]
+fn test_prefix_and_postfix_increment() {
+    assert_eq!(number("++[1][0]"), 2.0);
+    assert_eq!(number("--[1][0]"), 0.0);
+    assert_eq!(number("[1][0]++"), 1.0);
+    assert_eq!(number("[1][0]--"), 1.0);
+    assert_eq!(number("++[1, 2][0] ** 2"), 4.0);
+}
+
+#[test]
+fn test_update_evaluates_target_once() {
+    // Popping twice would fail on the second, now-empty list.
+    assert_eq!(number("[5, 6][[1].pop()]++"), 6.0);
+    assert_eq!(number("[5, 6][[0].pop()] += 10"), 15.0);
+}
+
+#[test]
+fn test_update_errors() {
+    assert_eq!(
+        error_message("[nil][0] += 1"),
+        "Operands must be two numbers or two strings."
+    );
+    assert_eq!(
+        error_message("[\"a\"][0]++"),
+        "Operands must be two numbers or two strings."
+    );
+    assert_eq!(
+        error_message("[1][1] -= 1"),
+        "List index 1 is out of range."
+    );
+    assert_eq!(error_message("{}[\"n\"]++"), "Undefined key 'n'.");
+}
+
+/// Each arm raises an error naming its own index, so the error shows
+/// which arm ran.
+fn matched_arm(source: &str) -> Option<String> {
+    execute(source).err().map(|error| error.message)
+}
+
+#[test]
+fn test_match_runs_first_matching_arm() {
+    let arms = "1 => [][1]; \"a\" or 2 => [][2]; _ => [][3]; }";
+
+    assert_eq!(
+        matched_arm(&format!("match (1) {{ {}", arms)),
+        Some("List index 1 is out of range.".to_string())
+    );
+    assert_eq!(
+        matched_arm(&format!("match (1 + 1) {{ {}", arms)),
+        Some("List index 2 is out of range.".to_string())
+    );
+    assert_eq!(
+        matched_arm(&format!("match (\"a\") {{ {}", arms)),
+        Some("List index 2 is out of range.".to_string())
+    );
+    assert_eq!(
+        matched_arm(&format!("match (nil) {{ {}", arms)),
+        Some("List index 3 is out of range.".to_string())
+    );
+}
+
+#[test]
+fn test_match_without_matching_arm_does_nothing() {
+    assert!(execute("match (3) { 1 => [][1]; 2 => [][2]; }").is_ok());
+}
+
+#[test]
+fn test_match_guards() {
+    assert_eq!(
+        matched_arm("match (-1) { -1 if false => [][1]; -1 if 1 < 2 => [][2]; }"),
+        Some("List index 2 is out of range.".to_string())
+    );
+    // A guard is only evaluated once its pattern has matched.
+    assert!(execute("match (1) { 2 if [][0] => 0; _ => 0; }").is_ok());
+}
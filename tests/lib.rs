@@ -5,7 +5,7 @@ use std::{
     panic::AssertUnwindSafe,
 };
 
-use lox_rs::{run, run_file, run_main, run_prompt, run_source};
+use lox_rs::{parse_source, run, run_file, run_main, run_prompt, run_source};
 use std::io::Cursor;
 use std::panic::catch_unwind;
 use tempfile::tempdir;
@@ -97,7 +97,6 @@ fn test_run_prompt_triggers_break_on_empty_input() {
 }
 
 #[test]
-#[should_panic(expected = "Unary error")]
 fn test_run_source_invalid_script() {
     let source = "invalid syntax".to_string();
     run_source(source);
@@ -225,3 +224,88 @@ fn test_run_main_with_no_arguments() {
     let output_str = String::from_utf8(output.borrow().to_vec()).unwrap();
     assert!(output_str.contains("lox>"));
 }
+
+#[test]
+fn test_run_source_with_string_interpolation() {
+    let source = "print \"one plus one is ${1 + 1}\";".to_string();
+    run_source(source);
+}
+
+#[test]
+fn test_run_source_with_list() {
+    let source = "print [1, 2, 3].slice(1, 3);".to_string();
+    run_source(source);
+}
+
+#[test]
+fn test_run_source_reports_runtime_error_without_panicking() {
+    let source = "print [1][5];".to_string();
+    run_source(source);
+}
+
+#[test]
+fn test_run_source_with_map() {
+    let source = "print {\"a\": 1, \"b\": 2}.keys();".to_string();
+    run_source(source);
+}
+
+#[test]
+fn test_parse_source_returns_statements() {
+    assert_eq!(parse_source("print 1; print 2;").map(|s| s.len()), Some(2));
+}
+
+#[test]
+fn test_parse_source_rejects_scan_errors() {
+    assert!(parse_source("print \"\\q\";").is_none());
+    assert!(parse_source("print \"\\u{D800}\";").is_none());
+}
+
+#[test]
+fn test_run_source_rejects_bad_number_literals() {
+    // The parser still sees what the malformed literal left behind, and
+    // must report it rather than panic.
+    assert!(parse_source("print 0x;").is_none());
+    run_source("print 1__0;".to_string());
+}
+
+#[test]
+fn test_parse_source_rejects_parse_errors() {
+    assert!(parse_source("print 1 = 2;").is_none());
+    assert!(parse_source("print 1").is_none());
+}
+
+#[test]
+fn test_parse_source_rejects_update_of_non_index_targets() {
+    assert!(parse_source("print 3--2;").is_none());
+    assert!(parse_source("print --5;").is_none());
+    assert!(parse_source("print 3++2;").is_none());
+}
+
+#[test]
+fn test_parse_source_rejects_invalid_match_patterns() {
+    assert!(parse_source("match (5) { x => print \"bound\"; }").is_none());
+    assert!(parse_source("match (\"a1\") { \"a${1}\" => print 1; }").is_none());
+}
+
+#[test]
+fn test_parse_source_rejects_malformed_postfix_expressions() {
+    assert!(parse_source("print [1, 2;").is_none());
+    assert!(parse_source("print {\"a\" 1};").is_none());
+    assert!(parse_source("print [1].;").is_none());
+}
+
+#[test]
+fn test_parse_source_rejects_conditional_without_else_branch() {
+    assert!(parse_source("print 1 ? 2;").is_none());
+}
+
+#[test]
+fn test_parse_source_rejects_empty_interpolation() {
+    assert!(parse_source("print \"a${}b\";").is_none());
+}
+
+#[test]
+fn test_parse_source_rejects_match_arm_without_body() {
+    assert!(parse_source("match (1) { x }").is_none());
+    assert!(parse_source("match (1) { 1 }").is_none());
+}